@@ -0,0 +1,58 @@
+//! Format String Helpers
+//!
+//! This module declares functions that adapt
+//! user provided format strings to the bindings
+//! generated by this crate's macros.
+
+/// **`FIELD_BINDING_PREFIX`**
+///
+/// The prefix of the bindings generated for variant
+/// fields, so they can't shadow the generated locals.
+pub const FIELD_BINDING_PREFIX: &str = "__field_";
+
+/// **`bind_format_arguments()`**
+///
+/// Rewrites the format arguments referencing variant fields,
+/// positional such as `{0}` or named after one of the `fields`
+/// such as `{id}`, into the bindings generated for them such as
+/// `{__field_0}` and `{__field_id}`. Escaped braces and any
+/// other argument are preserved.
+pub fn bind_format_arguments(format: &str, fields: &[String]) -> String {
+    let mut result = String::with_capacity(format.len());
+    let mut chars = format
+        .chars()
+        .peekable();
+
+    while let Some(current) = chars.next() {
+        result.push(current);
+
+        if current != '{' {
+            continue;
+        }
+
+        if chars.peek() == Some(&'{') {
+            result.push('{');
+            chars.next();
+            continue;
+        }
+
+        let mut name = String::new();
+
+        while let Some(next) = chars.next_if(|next| !matches!(next, ':' | '}')) {
+            name.push(next);
+        }
+
+        if name
+            .chars()
+            .all(|character| character.is_ascii_digit())
+            && !name.is_empty()
+            || fields.contains(&name)
+        {
+            result.push_str(FIELD_BINDING_PREFIX);
+        }
+
+        result.push_str(&name);
+    }
+
+    result
+}
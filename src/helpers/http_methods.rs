@@ -0,0 +1,48 @@
+//! HTTP Method Helpers
+//!
+//! Helpers for validating the methods declared in
+//! routes, either standard or extension methods.
+
+/// **`HTTP_METHODS`**
+///
+/// An array of HTTP method string literals, which
+/// have an `actix_web::http::Method` constant.
+pub const HTTP_METHODS: [&str; 9] =
+    ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS", "CONNECT", "TRACE"];
+
+/// **`is_method_token()`**
+///
+/// Whether the method is a valid RFC 7230 token, which
+/// is the syntax required for extension methods such
+/// as `PROPFIND` or `PURGE`.
+pub fn is_method_token(method: &str) -> bool {
+    !method.is_empty()
+        && method
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
+}
+
+/// **`normalize_method()`**
+///
+/// Uppercases the standard methods as they are case
+/// insensitive in routes, extension methods are kept as
+/// declared since methods are case sensitive. Returns
+/// None if the method is not a valid token.
+pub fn normalize_method(method: &str) -> Option<String> {
+    if let Some(standard) = standard_method(method) {
+        return Some(standard.to_string());
+    }
+
+    is_method_token(method).then(|| method.to_string())
+}
+
+/// **`standard_method()`**
+///
+/// The [`HTTP_METHODS`] entry matching the method
+/// ignoring the case, if any.
+pub fn standard_method(method: &str) -> Option<&'static str> {
+    HTTP_METHODS
+        .iter()
+        .find(|standard| standard.eq_ignore_ascii_case(method))
+        .copied()
+}
@@ -0,0 +1,45 @@
+//! Macro Attribute Helpers
+//!
+//! This module declares helpers to find and
+//! extend the attributes of this crate's macros
+//! when nested inside other items.
+
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{Attribute, Meta};
+
+/// **`is_macro_attr()`**
+///
+/// Whether the attribute invokes the macro with the
+/// provided name, either imported or by its full path.
+pub fn is_macro_attr(attr: &Attribute, name: &str) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
+
+/// **`append_macro_option()`**
+///
+/// Appends a `key = value` option to the arguments
+/// of a list attribute such as `#[proof_route(..)]`.
+pub fn append_macro_option(attr: &mut Attribute, option: &TokenStream2) {
+    let Meta::List(list) = &mut attr.meta else {
+        return;
+    };
+
+    let trailing_comma = list
+        .tokens
+        .clone()
+        .into_iter()
+        .last()
+        .is_some_and(|token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','));
+
+    if trailing_comma {
+        list.tokens
+            .extend(option.clone());
+    } else {
+        list.tokens
+            .extend(quote! { , #option });
+    }
+}
@@ -4,6 +4,12 @@
 //! and structures used to parse and generate
 //! the content for this crate's procedural macros.
 
+pub mod formatting;
+pub mod http_methods;
+pub mod macro_attrs;
+pub mod path_templates;
+pub mod response_formats;
 pub mod semantics;
 pub mod status_codes;
+pub mod templates;
 pub mod unique_attr;
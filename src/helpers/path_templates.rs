@@ -0,0 +1,155 @@
+//! Path Template Helpers
+//!
+//! This module declares the functions that validate
+//! route path templates the way `actix_web` parses
+//! them, so malformed paths fail at compile time.
+
+use regex::Regex;
+
+/// **`MAX_DYNAMIC_SEGMENTS`**
+///
+/// The maximum amount of dynamic segments
+/// `actix_web` allows in a path.
+const MAX_DYNAMIC_SEGMENTS: usize = 16;
+
+/// **`parse_path_template()`**
+///
+/// Validates a route path template and returns the names of its
+/// dynamic segments in order. Segments are written as `{name}`,
+/// `{name:regex}` or `{name}*` for tail segments. Returns an
+/// error message if the path doesn't start with a slash, has
+/// unbalanced braces, invalid or duplicate segment names or
+/// custom regex that doesn't compile.
+pub fn parse_path_template(path: &str) -> Result<Vec<String>, String> {
+    if !path.is_empty() && !path.starts_with('/') {
+        return Err(format!("The path \"{path}\" must start with a slash."));
+    }
+
+    let mut names = Vec::<String>::new();
+    let mut rest = path;
+
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err(format!("Found an unbalanced \"}}\" in the path \"{path}\"."));
+        }
+
+        let (name, after) = parse_dynamic_segment(&rest[start..], path)?;
+
+        if names.contains(&name) {
+            return Err(format!("The segment {name} is declared more than once."));
+        }
+
+        names.push(name);
+        rest = after;
+    }
+
+    if rest.ends_with('*') {
+        return Err("Tail segments must be named, such as \"{tail}*\".".to_string());
+    }
+
+    if names.len() > MAX_DYNAMIC_SEGMENTS {
+        return Err(format!("Only {MAX_DYNAMIC_SEGMENTS} dynamic segments are allowed."));
+    }
+
+    Ok(names)
+}
+
+/// **`normalize_path_template()`**
+///
+/// Removes the dynamic segment names of a route path template,
+/// keeping their custom regex, so paths matching the same
+/// requests compare equal, such as `/users/{id}` and
+/// `/users/{user_id}` which both normalize to `/users/{}`.
+pub fn normalize_path_template(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+    let mut rest = path;
+
+    while let Some(start) = rest.find('{') {
+        normalized.push_str(&rest[..start]);
+
+        let mut nesting = 0usize;
+        let Some(end) = rest[start..]
+            .find(|character| match character {
+                '{' => {
+                    nesting += 1;
+                    false
+                },
+                '}' => {
+                    nesting -= 1;
+                    nesting == 0
+                },
+                _ => false,
+            })
+            .map(|end| start + end)
+        else {
+            break;
+        };
+
+        normalized.push('{');
+
+        if let Some((_, pattern)) = rest[start + 1..end].split_once(':') {
+            normalized.push(':');
+            normalized.push_str(pattern);
+        }
+
+        normalized.push('}');
+        rest = &rest[end + 1..];
+    }
+
+    normalized.push_str(rest);
+    normalized
+}
+
+/// **`parse_dynamic_segment()`**
+///
+/// Parses the dynamic segment the `segment` starts with, balancing
+/// the braces of custom regex. Returns the segment name and the
+/// rest of the path after it.
+fn parse_dynamic_segment<'a>(segment: &'a str, path: &str) -> Result<(String, &'a str), String> {
+    let mut nesting = 0usize;
+    let Some(end) = segment.find(|character| match character {
+        '{' => {
+            nesting += 1;
+            false
+        },
+        '}' => {
+            nesting -= 1;
+            nesting == 0
+        },
+        _ => false,
+    }) else {
+        return Err(format!("Found an unbalanced \"{{\" in the path \"{path}\"."));
+    };
+
+    let param = &segment[1..end];
+    let mut after = &segment[end + 1..];
+    let tail = after == "*";
+
+    if tail {
+        after = "";
+    }
+
+    let (name, pattern) = param
+        .split_once(':')
+        .map_or((param, None), |(name, pattern)| (name, Some(pattern)));
+
+    if name.is_empty()
+        || name.starts_with(|character: char| character.is_ascii_digit())
+        || !name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '_')
+    {
+        return Err(format!("\"{name}\" is not a valid segment name."));
+    }
+
+    if let Some(pattern) = pattern {
+        if tail {
+            return Err(format!("The tail segment {name} can't declare a custom regex."));
+        }
+
+        Regex::new(&format!("(?P<{name}>{pattern})"))
+            .map_err(|err| format!("Invalid regex for the segment {name}, {err}"))?;
+    }
+
+    Ok((name.to_string(), after))
+}
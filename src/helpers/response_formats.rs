@@ -0,0 +1,69 @@
+//! Response Format Helpers
+//!
+//! Helpers for parsing the response formats
+//! an error body can be rendered as.
+
+use strsim::levenshtein;
+
+/// **`RESPONSE_FORMATS`**
+///
+/// A constant array associating response format
+/// identifiers to their content types.
+static RESPONSE_FORMATS: &[(&str, &str)] = &[
+    ("text", "text/plain; charset=utf-8"),
+    ("json", "application/json"),
+    ("problem_json", "application/problem+json"),
+    ("html", "text/html; charset=utf-8"),
+    ("jsonapi", "application/vnd.api+json"),
+    #[cfg(feature = "msgpack")]
+    ("msgpack", "application/msgpack"),
+    #[cfg(feature = "cbor")]
+    ("cbor", "application/cbor"),
+];
+
+/// **`FEATURE_GATED_FORMATS`**
+///
+/// A constant array associating response format
+/// identifiers to the crate feature enabling them.
+static FEATURE_GATED_FORMATS: &[(&str, &str)] = &[("msgpack", "msgpack"), ("cbor", "cbor")];
+
+/// **`allowed_formats()`**
+///
+/// A getter to the private **`RESPONSE_FORMATS`**.
+pub fn allowed_formats() -> &'static [(&'static str, &'static str)] {
+    RESPONSE_FORMATS
+}
+
+/// **`format_content_type()`**
+///
+/// Returns the content type for a response format
+/// identifier if it's supported, otherwise None.
+pub fn format_content_type(target_format: &str) -> Option<&'static str> {
+    RESPONSE_FORMATS
+        .iter()
+        .find(|(supported_format, _)| &target_format == supported_format)
+        .map(|(_, content_type)| *content_type)
+}
+
+/// **`format_feature()`**
+///
+/// Returns the crate feature required by a response
+/// format identifier, if any.
+pub fn format_feature(target_format: &str) -> Option<&'static str> {
+    FEATURE_GATED_FORMATS
+        .iter()
+        .find(|(gated_format, _)| &target_format == gated_format)
+        .map(|(_, feature)| *feature)
+}
+
+/// **`closest_format()`**
+///
+/// Applies the edit distance algorithm to a typoed
+/// response format identifier and returns the most-likely
+/// to be response format.
+pub fn closest_format(target_format: &str) -> &str {
+    RESPONSE_FORMATS
+        .iter()
+        .min_by_key(|(supported_format, _)| levenshtein(supported_format, target_format))
+        .map_or_else(|| unreachable!(), |(supported_format, _)| *supported_format)
+}
@@ -3,7 +3,17 @@
 //! This module declares functions that help
 //! validate semantics for parsed elements.
 
-use syn::{GenericArgument, PathArguments, Type, TypePath};
+use syn::{
+    Expr,
+    ExprCall,
+    ExprPath,
+    ExprStruct,
+    GenericArgument,
+    Ident,
+    PathArguments,
+    Type,
+    TypePath,
+};
 
 /// **`has_result_semantics()`**
 ///
@@ -38,3 +48,114 @@ pub fn has_result_semantics(ty: &Type) -> Option<(&Type, &Type)> {
 
     Some((t, e))
 }
+
+/// **`has_option_semantics()`**
+///
+/// Parses the semantics for an `Option` like type
+/// and returns the `T` parsed type.
+pub fn has_option_semantics(ty: &Type) -> Option<&Type> {
+    single_type_argument(ty, "Option")
+}
+
+/// **`PAYLOAD_EXTRACTORS`**
+///
+/// The `actix_web::web` extractors deserializing
+/// their `T` with `serde` from the request.
+const PAYLOAD_EXTRACTORS: [&str; 3] = ["Json", "Query", "Form"];
+
+/// **`has_payload_semantics()`**
+///
+/// Parses the semantics for a `web::Json<T>`, `web::Query<T>`
+/// or `web::Form<T>` extractor and returns the extractor
+/// name alongside the `T` parsed type.
+pub fn has_payload_semantics(ty: &Type) -> Option<(&'static str, &Type)> {
+    PAYLOAD_EXTRACTORS
+        .iter()
+        .find_map(|name| single_type_argument(ty, name).map(|inner| (*name, inner)))
+}
+
+/// **`PRIMITIVE_TYPES`**
+///
+/// The types deserialized from a single
+/// path segment by `actix_web::web::Path`.
+const PRIMITIVE_TYPES: [&str; 18] = [
+    "bool", "char", "str", "String", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16",
+    "i32", "i64", "i128", "isize", "f32", "f64",
+];
+
+/// **`has_path_semantics()`**
+///
+/// Parses the semantics for a `web::Path<T>` extractor
+/// and returns the `T` parsed type.
+pub fn has_path_semantics(ty: &Type) -> Option<&Type> {
+    single_type_argument(ty, "Path")
+}
+
+/// **`is_primitive_type()`**
+///
+/// Whether the type is a primitive deserialized
+/// from a single value such as `u32` or `String`.
+pub fn is_primitive_type(ty: &Type) -> bool {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+
+    path.get_ident()
+        .is_some_and(|ident| {
+            PRIMITIVE_TYPES.contains(
+                &ident
+                    .to_string()
+                    .as_str(),
+            )
+        })
+        || path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "String")
+}
+
+/// **`single_type_argument()`**
+///
+/// Returns the `T` of a type such as `Wrapper<T>`
+/// if its last path segment matches the name.
+fn single_type_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let last_seg = path
+        .segments
+        .last()?;
+
+    if last_seg.ident != name {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &last_seg.arguments else {
+        return None;
+    };
+
+    match args
+        .args
+        .first()?
+    {
+        GenericArgument::Type(t) => Some(t),
+        _ => None,
+    }
+}
+
+/// **`variant_ident()`**
+///
+/// The variant a variant expression such as `InvalidBody`,
+/// `InvalidQuery(..)` or `InvalidForm { .. }` constructs.
+pub fn variant_ident(variant: &Expr) -> Option<&Ident> {
+    let path = match variant {
+        Expr::Path(ExprPath { path, .. }) | Expr::Struct(ExprStruct { path, .. }) => path,
+        Expr::Call(ExprCall { func, .. }) => match &**func {
+            Expr::Path(ExprPath { path, .. }) => path,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    path.get_ident()
+}
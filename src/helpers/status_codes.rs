@@ -70,6 +70,17 @@ pub fn code_to_status(target_code: usize) -> Option<&'static str> {
         .map(|(_, supported_status)| *supported_status)
 }
 
+/// **`status_to_code()`**
+///
+/// Converts a status identifier to its numeric code and
+/// returns Some if it could be converted, otherwise None.
+pub fn status_to_code(target_status: &str) -> Option<usize> {
+    STATUS_CODES
+        .iter()
+        .find(|(_, supported_status)| &target_status == supported_status)
+        .map(|(supported_code, _)| *supported_code)
+}
+
 /// **`is_status_supported()`**
 ///
 /// Returns true if the `target_status` is supported
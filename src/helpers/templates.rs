@@ -0,0 +1,63 @@
+//! HTML Template Helpers
+//!
+//! This module declares the functions that split
+//! the `#[html_template(..)]` files into literal text
+//! and `{{placeholder}}` segments.
+
+/// **`TEMPLATE_BUILTINS`**
+///
+/// The placeholders that don't reference a variant
+/// field, these take precedence over fields with
+/// the same name.
+pub const TEMPLATE_BUILTINS: &[&str] = &["message", "status"];
+
+/// **`TemplateSegment`**
+///
+/// A piece of a parsed template, either text
+/// rendered as is or a placeholder name.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TemplateSegment {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// **`parse_template()`**
+///
+/// Splits a template into its segments, placeholders
+/// are written as `{{name}}` and may contain surrounding
+/// whitespace. Returns an error message if a placeholder
+/// is not closed or its name is not an identifier or index.
+pub fn parse_template(template: &str) -> Result<Vec<TemplateSegment>, String> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            segments.push(TemplateSegment::Literal(rest[..start].to_string()));
+        }
+
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            return Err("Found an unclosed \"{{\" placeholder.".to_string());
+        };
+
+        let name = after[..end].trim();
+
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '_')
+        {
+            return Err(format!("Invalid placeholder \"{{{{{}}}}}\".", &after[..end]));
+        }
+
+        segments.push(TemplateSegment::Placeholder(name.to_string()));
+        rest = &after[end + 2..];
+    }
+
+    if !rest.is_empty() {
+        segments.push(TemplateSegment::Literal(rest.to_string()));
+    }
+
+    Ok(segments)
+}
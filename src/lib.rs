@@ -8,12 +8,18 @@
 #![warn(clippy::unwrap_used)]
 
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use syn::parse::ParseStream;
+use syn::{Error as SynError, parse_macro_input};
 
-use crate::macro_input::error_response::ErrorResponse;
+use crate::macro_input::error_response::{ErrorResponse, ResolvedVariant};
+use crate::macro_input::proof_controller::{ProofControllerBody, ProofControllerMeta};
 use crate::macro_input::proof_route::{ProofRouteBody, ProofRouteMeta};
-use crate::macro_output::error_response::error_response_output;
+use crate::macro_input::proof_scope::{ProofScopeBody, ProofScopeMeta};
+use crate::macro_output::error_response::{error_response_output, resolved_variant_output};
+use crate::macro_output::proof_controller::proof_controller_output;
 use crate::macro_output::proof_route::proof_route_output;
+use crate::macro_output::proof_scope::proof_scope_output;
+use crate::macro_output::route_registry::route_registry_output;
 
 mod helpers;
 mod macro_input;
@@ -55,6 +61,78 @@ mod tests;
 /// Like `default_status_code` you can pass a number or an HTTP status code
 /// identifier and it will be applied to the current enum variant.
 ///
+/// **`#[message(lang = "language_tag", "format string")]`**
+/// You can add this attribute many times to an enum variant, once per
+/// language, to declare a translated message. The format string can reference
+/// the variant fields like `thiserror` does, i.e. `{field}` or `{0}`. When an
+/// error is returned from a [`proof_route`] handler the language is negotiated
+/// from the request `Accept-Language` header, otherwise and when no language
+/// matches, the enum's Display is used. Every variant must declare a message
+/// for every language used in the enum, missing translations fail compilation.
+///
+/// **`#[response_format(format, ..)]`**
+/// You can add this attribute to your enum and pass one or many of `text`,
+/// `json`, `problem_json`, `html` or `jsonapi`. When an error is returned from
+/// a [`proof_route`] handler the format is negotiated from the request `Accept`
+/// header, the first format being the default when the client accepts anything
+/// or the error is converted without a request. The `json` format renders
+/// `{"status": .., "code": "Variant", "message": ".."}` while `problem_json`
+/// renders an RFC 9457 problem details object and `jsonapi` renders a JSON:API
+/// `{"errors": [..]}` document. This attribute can't be used together with
+/// `transform_response`.
+///
+/// With the `msgpack` and `cbor` crate features enabled, the `msgpack` and
+/// `cbor` formats are also available. Those encode a structured envelope with
+/// the `status`, `code`, `message` and a `fields` map containing the variant
+/// fields annotated with `#[expose]`, using your crate's `serde` alongside
+/// `rmp_serde` or `ciborium` which need to be dependencies of your crate.
+///
+/// **`#[expose]`**
+/// You can add this attribute to variant fields implementing `serde::Serialize`
+/// to include them in the `fields` map of the binary formats envelope, tuple
+/// variant fields are keyed by their position.
+///
+/// **`#[source_pointer("/json/pointer")]`** and
+/// **`#[source_parameter("name")]`** You can add these attributes to an enum
+/// variant to fill the `source` member of the `jsonapi` format error object,
+/// they require the `jsonapi` format to be declared.
+///
+/// **`#[html_template("path/to/template.html")]`**
+/// You can add this attribute to your enum or to a specific variant to render
+/// errors as `text/html` pages from a template, the path is relative to your
+/// `CARGO_MANIFEST_DIR` and the file is embedded at compile time. Templates may
+/// contain `{{message}}`, `{{status}}` and `{{field}}` placeholders, the latter
+/// referencing variant fields by name or position, all of them escaped. With
+/// `#[response_format(..)]` the template is used for the `html` format, which
+/// must then be declared.
+///
+/// **`#[not_acceptable(reject_or_default)]`**
+/// You can add this attribute to your enum to decide what happens when the
+/// request doesn't accept any of the declared response formats, `default`
+/// renders the default format which is the behavior if the attribute is not
+/// present, while `reject` responds with an empty `406 Not Acceptable`.
+///
+/// **`#[extractor_errors(Extractor => EnumVariant, ..)]`**
+/// You can add this attribute to your enum to render the errors of each
+/// extractor type of [`proof_route`] handlers as a variant, such as
+/// `#[extractor_errors(Json => InvalidBody, Query => InvalidQuery)]`. The
+/// keys are `Json`, `Query`, `Path`, `Form`, `Header` and `Cookie`, which map
+/// the `actix_web::web` extractor types, aliases included, through a hidden
+/// trait implementation resolved at compile time. The `#[path]`, `#[query]`,
+/// `#[header(..)]` and `#[cookie(..)]` parameters match `Path`, `Query`,
+/// `Header` and `Cookie`, while wrappers such as `Option<Json<T>>` aren't
+/// mapped. The variant expressions can use the
+/// `err` and `req` bindings, parameter overrides and the route
+/// `extractor_error` take precedence over this attribute.
+///
+/// **`#[extractor_fallback(EnumVariant)]`**
+/// You can add this attribute to your enum to render the extractor errors of
+/// [`proof_route`] handlers as one of its variants instead of the plain text
+/// `actix_web` error, so they share the enum format and transformer. Like
+/// `#[error_override(..)]`, the variant expression can use the `err` and `req`
+/// bindings. Parameter overrides, the route `extractor_error` and the
+/// `#[extractor_errors(..)]` table take precedence over this attribute.
+///
 /// By default all status codes will be `InternalServerError` and the enum's
 /// Display will be applied to the response body.
 ///
@@ -92,7 +170,20 @@ mod tests;
 /// ```
 #[proc_macro_derive(
     ErrorResponse,
-    attributes(default_status_code, status_code, transform_response)
+    attributes(
+        default_status_code,
+        status_code,
+        transform_response,
+        message,
+        response_format,
+        not_acceptable,
+        expose,
+        source_pointer,
+        source_parameter,
+        html_template,
+        extractor_errors,
+        extractor_fallback
+    )
 )]
 pub fn error_response(input: TokenStream) -> TokenStream {
     error_response_output(&parse_macro_input!(input as ErrorResponse)).into()
@@ -104,13 +195,44 @@ pub fn error_response(input: TokenStream) -> TokenStream {
 /// `proof_route` with the following syntax `#[proof_route("METHOD /path")]`
 /// resembling to the HTTP standard syntax.
 ///
+/// A handler may be routed for several methods separated by `|` and several
+/// paths by declaring more routes, such as
+/// `#[proof_route("GET|HEAD /files/{id}", "GET /download/{id}")]`, declaring
+/// the same method and path twice is an error.
+///
+/// Besides the standard methods, extension methods such as `PROPFIND` or
+/// `PURGE` are accepted if they are valid RFC 7230 method tokens, these are
+/// case sensitive and can't contain `|` as it separates the methods.
+///
+/// Paths are validated at compile time, they must start with a slash and
+/// their `{segment}`, `{segment:regex}` and `{tail}*` segments must be named
+/// uniquely with balanced braces and valid regex. The `web::Path` extractors
+/// are checked against the segments of the path, the controller prefix and
+/// the `scope_prefix`, primitives such as `Path<u32>` must be extracted from
+/// a single segment and tuples can't take more values than the segments. With
+/// several segments, struct extractors must declare a field named after each
+/// segment, so fields renamed with `serde` and tuple aliases aren't supported,
+/// extract those segments with a tuple or `#[path]` parameters instead.
+///
 /// **Before using this macro see [`ErrorResponse`] as you need it to use this**
 ///
 /// This macro creates a new `actix_web` route, the syntax is the same as normal
 /// attribute marked routes, except the return type changes to be a `Result<T,
-/// E>` where `T` should implement `::actix_web::Responder` and `E` should
-/// implement `Into<::actix_web::HttpResponse>` which you can implement in your
-/// response type by using [`ErrorResponse`].
+/// E>` where `T` should implement `::actix_web::Responder`, which may also be
+/// written as `impl Responder`, and `E` should implement
+/// `Into<::actix_web::HttpResponse>` which you can implement in your response
+/// type by using [`ErrorResponse`].
+///
+/// The generated service inherits the handler visibility, doc comments and
+/// `cfg`, `cfg_attr`, `deprecated` and lint attributes, so handlers declared
+/// `pub` in a module can be registered anywhere and feature gated handlers
+/// are only compiled when the feature is enabled. Public handlers without doc
+/// comments are reported by the `missing_docs` lint like any other item.
+///
+/// Generic handlers, declaring type or const parameters, generate a function
+/// with the same generics returning the service instead, so they are registered
+/// for concrete arguments such as `App::new().service(list::<PgRepo>())`. The
+/// type parameters used in extractors must be `'static`.
 ///
 /// Since this thightly integrates with with `thiserror` you can join multiple
 /// error types and use `?` to make your error handling in routes ergonomic.
@@ -118,6 +240,72 @@ pub fn error_response(input: TokenStream) -> TokenStream {
 /// If you return a custom error not annotated with [`ErrorResponse`] this is
 /// considered undefined behavior, and no support will be given to that.
 ///
+/// ## Macro Options
+///
+/// **`expose_inner = "function_name"`**
+///
+/// The original handler is nested inside the generated service, so it can't be
+/// called directly. With `#[proof_route("GET /x", expose_inner =
+/// "get_x_inner")]` the original function is declared next to the service with
+/// the provided name and the handler visibility, so it can be called in plain
+/// unit tests with constructed extractors.
+///
+/// **`error = ErrorType`**
+///
+/// By default the return type must be written as `Result<T, E>`, to return a
+/// `Result` alias such as `ApiResult<T>` or `io::Result<T>` declare the error
+/// type with `#[proof_route("GET /x", error = ApiError)]`.
+///
+/// **`name = "resource_name"`**
+///
+/// Names the resource for `HttpRequest::url_for`, by default it is named after
+/// the handler, or `Controller::method` in a [`proof_controller`]. With several
+/// paths only the first one is named.
+///
+/// **`guard = "guard_function"`**
+///
+/// Adds a `fn(&GuardContext) -> bool` guard to the route, may be declared
+/// several times.
+///
+/// **`wrap = "Middleware::new()"`**
+///
+/// Wraps the route with a middleware expression, may be declared several
+/// times, the last one declared being the outermost.
+///
+/// **`extractor_error = EnumVariant`**
+///
+/// Renders the errors of every extractor without its own
+/// `#[error_override(..)]` as this variant of the handler error, which can use
+/// the `err` and `req` bindings, taking precedence over the enum
+/// `#[extractor_fallback(..)]`.
+///
+/// **`path_to_error = true`**
+///
+/// With the `serde_path_to_error` crate feature enabled, the `Json`, `Query`
+/// and `Form` extractors of the handler are deserialized with
+/// `serde_path_to_error`, which together with `serde`, `serde_json`,
+/// `serde_urlencoded` and `form_urlencoded` needs to be a dependency of your
+/// crate. Deserialization errors read like `address.zip: invalid type: ..` and
+/// the error overrides can also use the `path` binding with the segments of
+/// the failing field, empty at the root, and the `problem` binding with the
+/// error without the path. A missing field is reported at the path of the
+/// object missing it. The `expected` binding is an `Option<String>` with the
+/// `, expected ..` part of the serde message, such as `u32` for an invalid
+/// type, and `None` for errors without one, such as a missing field.
+///
+/// **`auto_register = true`**
+///
+/// Requires the `auto-register` feature, submits the handler to the
+/// [`route_registry!`] registry of your crate instead of registering it
+/// manually. Generic handlers can't be submitted.
+///
+/// **`scope_prefix = "/prefix"`**
+///
+/// Declares the path of the scopes the handler is registered in, so the
+/// `#[path]` parameters can name their dynamic segments. [`proof_scope`]
+/// declares it for its routes, declare it for the routes registered in a
+/// `web::scope` with dynamic segments.
+///
 /// ## Macro Attributes
 ///
 /// **`#[error_override(EnumVariant)]`**
@@ -127,6 +315,64 @@ pub fn error_response(input: TokenStream) -> TokenStream {
 /// This will replace any error that may be returned by the collector itself for
 /// a custom error variant instead.
 ///
+/// The variant expression can use the `err` binding, the original error as an
+/// `actix_web::Error`, and the `req` binding, the `&HttpRequest` being handled,
+/// such as `#[error_override(InvalidBody { reason: err.to_string() })]`.
+///
+/// Misspelled variants are reported at the attribute along with the closest
+/// variant of the error enumerable. The variants are resolved through a hidden
+/// macro the `ErrorResponse` derive declares next to the enumerable, so the
+/// error type must name the enumerable itself, declared in the same crate,
+/// rather than a type alias of it.
+///
+/// **`#[error_override(with = function)]`**
+///
+/// Maps the original error with a function taking
+/// `(actix_web::Error, &HttpRequest)` and returning the handler error instead.
+///
+/// **`#[error_override(Kind => EnumVariant, ..)]`**
+///
+/// Renders each kind of `JsonPayloadError`, `UrlencodedError`,
+/// `QueryPayloadError` and `PathError` as its own variant, where the kinds are
+/// `ContentType`, `Overflow`, `UnknownLength`, `Chunked`, `Encoding`, `Parse`,
+/// `Deserialize`, `Serialize` and `Payload`, such as
+/// `#[error_override(ContentType => WrongMediaType, Overflow => TooLarge, _ =>
+/// InvalidBody)]`. Arms may match several kinds with `|`, the errors no arm
+/// matches are handled as if the parameter had no override unless a last `_`
+/// arm is declared.
+///
+/// `JsonPayloadError` deserialization errors can also be matched by their
+/// `serde_json` category with the `Syntax`, `Data` and `Eof` kinds, so broken
+/// or truncated JSON can be rendered as a bad request and JSON with the wrong
+/// shape as an unprocessable entity, these are also matched by `Deserialize`.
+///
+/// **`#[validate(backend = validator, on_error = EnumVariant)]`**
+///
+/// With the `validator` or `garde` crate features enabled, parameters annotated
+/// with `validate` are validated after the extraction with the `Validate` trait
+/// of that crate, which needs to be a dependency of your crate. Failures are
+/// rendered as the `on_error` variant, which can use the `err` binding with the
+/// validation error, the `fields` binding with a
+/// `BTreeMap<String, Vec<String>>` of the messages per field and the `req`
+/// binding, or respond with a `422 Unprocessable Entity` if not declared. The
+/// `backend` is always required, as another crate enabling the other feature
+/// would otherwise change which one is used.
+///
+/// **`#[path]`, `#[query]`, `#[header("name")]` and `#[cookie("name")]`**
+///
+/// Instead of an extractor type, parameters annotated with a source attribute
+/// are looked up by name in the path segments, query string, headers or
+/// cookies and parsed with `FromStr`, such as `#[path] id: u64` or
+/// `#[header("X-Api-Key")] key: String`. The name defaults to the parameter
+/// name, with dashes instead of underscores for headers. `Option<T>` parameters
+/// are `None` when missing, otherwise missing or unparseable values respond
+/// with a `404 Not Found` for paths and `400 Bad Request` for the rest, unless
+/// an `#[error_override(..)]` variant is declared. Header values that aren't
+/// visible ASCII and query strings that can't be parsed are reported as
+/// invalid rather than missing. The `#[path]`
+/// names must be segments of every route path, the controller prefix or the
+/// `scope_prefix`, otherwise the handler fails to compile.
+///
 /// ## Example
 ///
 /// ```rust
@@ -163,9 +409,258 @@ pub fn error_response(input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_attribute]
 pub fn proof_route(meta: TokenStream, body: TokenStream) -> TokenStream {
-    proof_route_output(
-        &parse_macro_input!(meta as ProofRouteMeta),
-        &parse_macro_input!(body as ProofRouteBody),
-    )
-    .into()
+    let meta = parse_macro_input!(meta as ProofRouteMeta);
+    let body_parser = |input: ParseStream| ProofRouteBody::parse_with_error(input, meta.error());
+    let body = parse_macro_input!(body with body_parser);
+
+    if let Some(receiver) = body.receiver() {
+        return SynError::new_spanned(
+            receiver,
+            "self receivers are only allowed in #[proof_controller] impl blocks.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if let Err(error) = body
+        .validate_path_extractors(&meta, "", &[])
+        .and_then(|()| body.validate_path_sources(&meta, "", &[]))
+    {
+        return error
+            .to_compile_error()
+            .into();
+    }
+
+    proof_route_output(&meta, &body).into()
+}
+
+/// # `proof_controller` Attribute Macro
+///
+/// You can group [`proof_route`] handlers as methods of an `impl` block with
+/// `#[proof_controller("/prefix")]`, the methods annotated with
+/// `#[proof_route("METHOD /path")]` are registered under the prefix by the
+/// generated `configure` function, which can be passed to
+/// `App::configure` or `Scope::configure`. The prefix is validated like the
+/// [`proof_route`] paths and its segments can be named by `#[path]` parameters.
+///
+/// Methods receiving `&self` or `self: &Self` obtain the controller from the
+/// `web::Data<Self>` registered in the app, the request fails like any other
+/// missing `web::Data` if it wasn't registered. Methods without a receiver are
+/// also allowed, and since the methods are kept in the `impl` block they can be
+/// called directly.
+///
+/// Every [`proof_route`] option and attribute is supported except
+/// `expose_inner` and `auto_register`, generic impl blocks and generic methods
+/// are not supported.
+///
+/// ## Macro Options
+///
+/// **`auto_register = true`**
+///
+/// Requires the `auto-register` feature, submits the controller `configure`
+/// to the [`route_registry!`] registry of your crate instead of registering
+/// the controller manually.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_failwrap::{ErrorResponse, proof_controller};
+/// use thiserror::Error;
+/// use actix_web::web::{Data, Path};
+/// use actix_web::{App, HttpResponse};
+///
+/// #[derive(ErrorResponse, Error, Debug)]
+/// enum UserError {
+///     #[error("The user was not found.")]
+///     #[status_code(NotFound)]
+///     NotFound,
+/// }
+///
+/// struct UsersController {
+///     names: Vec<String>,
+/// }
+///
+/// #[proof_controller("/users")]
+/// impl UsersController {
+///     #[proof_route("GET /{id}")]
+///     async fn get(&self, id: Path<usize>) -> Result<HttpResponse, UserError> {
+///         self.names
+///             .get(id.into_inner())
+///             .map(|name| HttpResponse::Ok().body(name.clone()))
+///             .ok_or(UserError::NotFound)
+///     }
+/// }
+///
+/// let app = App::new()
+///     .app_data(Data::new(UsersController { names: vec!["Ferris".into()] }))
+///     .configure(UsersController::configure);
+/// ```
+#[proc_macro_attribute]
+pub fn proof_controller(meta: TokenStream, body: TokenStream) -> TokenStream {
+    let meta = parse_macro_input!(meta as ProofControllerMeta);
+    let body = parse_macro_input!(body as ProofControllerBody);
+
+    if let Err(error) = body.validate_paths(&meta) {
+        return error
+            .to_compile_error()
+            .into();
+    }
+
+    proof_controller_output(&meta, &body).into()
+}
+
+/// # `proof_scope` Attribute Macro
+///
+/// You can annotate an inline module with `#[proof_scope("/prefix")]` to
+/// generate a `pub fn configure(cfg: &mut web::ServiceConfig)` inside it,
+/// which registers under the prefix every [`proof_route`] handler declared in
+/// the module, the [`proof_controller`] impl blocks and the nested
+/// `proof_scope` modules, so they can't be forgotten.
+///
+/// Generic handlers can't be registered automatically, register their
+/// factories in a manual `configure` function instead.
+///
+/// ## Macro Options
+///
+/// **`error = ErrorType`**
+///
+/// Declares the default `error = ..` option for the [`proof_route`] handlers
+/// and nested scopes that don't declare their own.
+///
+/// **`extractor_error = EnumVariant`**
+///
+/// Declares the default `extractor_error = ..` option for the handlers and
+/// nested scopes inheriting the scope `error = ..`, which it requires, and
+/// not declaring their own.
+///
+/// **`scope_prefix = "/prefix"`**
+///
+/// Declares the path of the scopes this scope is nested in, passed down to
+/// the nested handlers for their `#[path]` parameters. Nested scopes declare
+/// it from their parent scope.
+///
+/// **`auto_register = true`**
+///
+/// Requires the `auto-register` feature, submits the scope `configure` to the
+/// [`route_registry!`] registry of your crate instead of registering the scope
+/// manually. The items nested in a scope are never submitted on their own, as
+/// the scope already registers them.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_failwrap::{ErrorResponse, proof_scope};
+/// use thiserror::Error;
+/// use actix_web::App;
+///
+/// #[derive(ErrorResponse, Error, Debug)]
+/// pub enum ApiError {
+///     #[error("The user was not found.")]
+///     #[status_code(NotFound)]
+///     NotFound,
+/// }
+///
+/// pub type ApiResult<T> = Result<T, ApiError>;
+///
+/// #[proof_scope("/api/v1", error = crate::ApiError)]
+/// mod api {
+///     use actix_failwrap::{proof_route, proof_scope};
+///     use actix_web::HttpResponse;
+///
+///     use crate::{ApiError, ApiResult};
+///
+///     #[proof_route("GET /health")]
+///     async fn health() -> ApiResult<HttpResponse> {
+///         Ok(HttpResponse::Ok().finish())
+///     }
+///
+///     #[proof_scope("/users")]
+///     mod users {
+///         use actix_failwrap::proof_route;
+///         use actix_web::HttpResponse;
+///
+///         use crate::{ApiError, ApiResult};
+///
+///         #[proof_route("GET /{id}")]
+///         async fn get_user() -> ApiResult<HttpResponse> {
+///             Err(ApiError::NotFound)
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let app = App::new().configure(api::configure);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn proof_scope(meta: TokenStream, body: TokenStream) -> TokenStream {
+    let meta = parse_macro_input!(meta as ProofScopeMeta);
+    let body_parser = |input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta);
+    let body = parse_macro_input!(body with body_parser);
+
+    proof_scope_output(&meta, &body).into()
+}
+
+/// # `route_registry` Macro
+///
+/// Requires the `auto-register` feature, with it every [`proof_route`]
+/// handler, [`proof_controller`] and top level [`proof_scope`] declaring
+/// `auto_register = true` submits itself to a link-time registry, so they
+/// don't need to be registered by hand. Submissions are opt-in per item, so
+/// crates which don't declare a registry are unaffected by the feature.
+///
+/// Since this crate only exports macros the registry lives in your crate,
+/// invoke `route_registry!()` once in the crate root to declare it alongside
+/// a `register_all(cfg: &mut web::ServiceConfig)` function, which registers
+/// every submitted item and can be passed to `App::configure`. Your crate must
+/// also depend on `inventory`.
+///
+/// `register_all` panics at startup listing the duplicate method and path
+/// pairs if any, comparing the paths without their segment names, as
+/// `actix_web` would otherwise silently let the first one win. Routes
+/// registered by hand aren't part of the check, so their collisions with the
+/// submitted items are still resolved silently by `actix_web`. The registration
+/// order is unspecified, and generic handlers are never submitted as they need
+/// concrete arguments.
+///
+/// ## Example
+///
+/// ```rust ignore
+/// use actix_failwrap::{proof_route, route_registry};
+/// use actix_web::{App, HttpResponse};
+///
+/// route_registry!();
+///
+/// #[proof_route("GET /health", auto_register = true)]
+/// async fn health() -> Result<HttpResponse, ApiError> {
+///     Ok(HttpResponse::Ok().finish())
+/// }
+///
+/// let app = App::new().configure(register_all);
+/// ```
+#[proc_macro]
+pub fn route_registry(input: TokenStream) -> TokenStream {
+    if let Some(token) = proc_macro2::TokenStream::from(input)
+        .into_iter()
+        .next()
+    {
+        return SynError::new_spanned(token, "route_registry!() doesn't take any arguments.")
+            .to_compile_error()
+            .into();
+    }
+
+    route_registry_output().into()
+}
+
+/// # `__failwrap_variant` Macro
+///
+/// Hidden macro invoked by the macro the `ErrorResponse` derive
+/// generates for each enum, which receives the variant names of the
+/// enum and resolves the variant expressions of the route overrides.
+/// Misspelled variants are reported at the attribute along with the
+/// closest existing variant.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __failwrap_variant(input: TokenStream) -> TokenStream {
+    resolved_variant_output(&parse_macro_input!(input as ResolvedVariant)).into()
 }
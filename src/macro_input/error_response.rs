@@ -5,24 +5,46 @@
 //! macro.
 
 use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
 
 use quote::format_ident;
+use strsim::levenshtein;
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::{
+    Attribute,
     Error as SynError,
+    Expr,
     Ident,
+    Index,
     ItemEnum,
     LitInt,
+    LitStr,
+    Member,
     Result as SynResult,
+    Token,
+    Type,
+    TypePath,
     Variant as EnumVariant,
+    Visibility,
+    bracketed,
 };
 
+use crate::helpers::response_formats::{
+    allowed_formats,
+    closest_format,
+    format_content_type,
+    format_feature,
+};
+use crate::helpers::semantics::variant_ident;
 use crate::helpers::status_codes::{
     allowed_status_pairs,
     closest_status,
     code_to_status,
     is_status_supported,
 };
+use crate::helpers::templates::{TEMPLATE_BUILTINS, TemplateSegment, parse_template};
 use crate::helpers::unique_attr::get_single_attr;
 
 /// **`ErrorResponse`**
@@ -31,8 +53,15 @@ use crate::helpers::unique_attr::get_single_attr;
 #[derive(Debug)]
 pub struct ErrorResponse {
     enum_name: Ident,
+    visibility: Visibility,            // for the hidden extractor error table.
     default_status_code: Ident,        // by default 500. Dynamic
     transform_response: Option<Ident>, // an onscope reference Fn(HttpStatusCode, &str)
+    languages: Vec<String>,            // every language found in #[message(..)]
+    response_formats: Vec<ResponseFormat>, // the first one is the default.
+    reject_not_acceptable: bool,       // respond 406 instead of the default format.
+    html_template: Option<HtmlTemplate>, // used by variants without their own template.
+    extractor_errors: Vec<ExtractorError>, // by extractor for errors without an override.
+    extractor_fallback: Option<Expr>,  // for extractor errors without an override.
     variants: Vec<ErrorResponseVariant>,
 }
 
@@ -42,9 +71,72 @@ pub struct ErrorResponse {
 #[derive(Debug)]
 pub struct ErrorResponseVariant {
     status_code: Option<Ident>,
+    messages: Vec<LocalizedMessage>,
+    exposed_fields: Vec<Member>,
+    source_pointer: Option<LitStr>,   // JSON:API source.pointer
+    source_parameter: Option<LitStr>, // JSON:API source.parameter
+    html_template: Option<HtmlTemplate>,
     variant: EnumVariant,
 }
 
+/// **`LocalizedMessage`**
+///
+/// Parsed meta for `#[message(lang = "..", "..")]`
+/// variant attributes.
+#[derive(Debug)]
+pub struct LocalizedMessage {
+    lang: LitStr,
+    format: LitStr,
+}
+
+/// **`HtmlTemplate`**
+///
+/// Parsed meta for `#[html_template(..)]`, the template
+/// file is read while parsing and split into segments.
+#[derive(Debug)]
+pub struct HtmlTemplate {
+    path: LitStr,
+    file: String, // the resolved path, embedded to track changes.
+    segments: Vec<TemplateSegment>,
+}
+
+/// **`EXTRACTOR_KEYS`**
+///
+/// The extractors `#[extractor_errors(..)]` can map, the
+/// `actix_web::web` extractor types plus `Path`, `Query`,
+/// `Header` and `Cookie` for the `#[path]`, `#[query]`,
+/// `#[header]` and `#[cookie]` parameter sources.
+pub const EXTRACTOR_KEYS: [&str; 6] = ["Json", "Query", "Path", "Form", "Header", "Cookie"];
+
+/// **`ExtractorError`**
+///
+/// Parsed meta for each `Extractor => Variant` pair
+/// of the `#[extractor_errors(..)]` attribute.
+#[derive(Debug)]
+pub struct ExtractorError {
+    extractor: Ident,
+    variant: Expr,
+}
+
+/// **`ResolvedVariant`**
+///
+/// Parsed meta for the hidden `__failwrap_variant!` calls,
+/// made by the per-enum macro the derive generates with the
+/// variant names of the enum, the error type as written by
+/// the route and the variant expression to resolve.
+#[derive(Debug)]
+pub struct ResolvedVariant {
+    error: Type,
+    variant: Expr,
+}
+
+/// **`ResponseFormat`**
+///
+/// Parsed meta for the formats an error body
+/// can be rendered as.
+#[derive(Debug)]
+pub struct ResponseFormat(Ident);
+
 /// **`StatusCode`**
 ///
 /// Parsed meta for HTTP status codes.
@@ -62,6 +154,15 @@ impl ErrorResponse {
         &self.enum_name
     }
 
+    /// **`ErrorResponse.visibility`**
+    ///
+    /// The visibility of the enum, the extractor error
+    /// table is declared with it.
+    #[inline]
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
     /// **`ErrorResponse.default_status_code`**
     ///
     /// The parsed default status code from `#[default_status_code(..)]`
@@ -81,6 +182,83 @@ impl ErrorResponse {
             .as_ref()
     }
 
+    /// **`ErrorResponse.languages`**
+    ///
+    /// Every language declared with `#[message(lang = "..", ..)]`
+    /// in declaration order, empty if there are no translations.
+    #[inline]
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    /// **`ErrorResponse.response_formats`**
+    ///
+    /// The formats declared with `#[response_format(..)]`,
+    /// the first one being the default, empty if the attribute
+    /// is not present.
+    #[inline]
+    pub fn response_formats(&self) -> &[ResponseFormat] {
+        &self.response_formats
+    }
+
+    /// **`ErrorResponse.reject_not_acceptable`**
+    ///
+    /// Whether `#[not_acceptable(reject)]` was declared, meaning
+    /// a 406 should be returned if no format matches the request.
+    #[inline]
+    pub const fn reject_not_acceptable(&self) -> bool {
+        self.reject_not_acceptable
+    }
+
+    /// **`ErrorResponse.html_template`**
+    ///
+    /// The template declared with `#[html_template(..)]` in
+    /// the enum if any, variants may override it.
+    #[inline]
+    pub const fn html_template(&self) -> Option<&HtmlTemplate> {
+        self.html_template
+            .as_ref()
+    }
+
+    /// **`ErrorResponse.has_html_templates`**
+    ///
+    /// Whether the enum or any of its variants declares
+    /// an `#[html_template(..)]`.
+    #[inline]
+    pub fn has_html_templates(&self) -> bool {
+        self.html_template
+            .is_some()
+            || self
+                .variants
+                .iter()
+                .any(|variant| {
+                    variant
+                        .html_template
+                        .is_some()
+                })
+    }
+
+    /// **`ErrorResponse.extractor_errors`**
+    ///
+    /// The variants declared with `#[extractor_errors(..)]`
+    /// for the errors of each extractor type, empty if the
+    /// attribute is not present.
+    #[inline]
+    pub fn extractor_errors(&self) -> &[ExtractorError] {
+        &self.extractor_errors
+    }
+
+    /// **`ErrorResponse.extractor_fallback`**
+    ///
+    /// The variant declared with `#[extractor_fallback(..)]`
+    /// that extractor errors are rendered as when neither the
+    /// parameter nor the route override them.
+    #[inline]
+    pub const fn extractor_fallback(&self) -> Option<&Expr> {
+        self.extractor_fallback
+            .as_ref()
+    }
+
     /// **`ErrorResponse.variants`**
     ///
     /// The annotated error enum variants.
@@ -95,6 +273,7 @@ impl Parse for ErrorResponse {
         let input = input.parse::<ItemEnum>()?;
 
         let enum_name = input.ident;
+        let visibility = input.vis;
 
         let default_status_code = get_single_attr(
             input
@@ -107,27 +286,62 @@ impl Parse for ErrorResponse {
         .map(StatusCode::into_inner)
         .unwrap_or(format_ident!("InternalServerError"));
 
-        let transform_response = get_single_attr(input.attrs, "transform_response")?
-            .map(|attr| attr.parse_args::<Ident>())
-            .transpose()?;
+        let transform_response = get_single_attr(
+            input
+                .attrs
+                .clone(),
+            "transform_response",
+        )?
+        .map(|attr| attr.parse_args::<Ident>())
+        .transpose()?;
+
+        let response_formats = get_single_attr(
+            input
+                .attrs
+                .clone(),
+            "response_format",
+        )?
+        .map(|attr| parse_response_formats(&attr, transform_response.as_ref()))
+        .transpose()?
+        .unwrap_or_default();
+
+        let html_template = get_single_attr(
+            input
+                .attrs
+                .clone(),
+            "html_template",
+        )?
+        .map(|attr| attr.parse_args::<HtmlTemplate>())
+        .transpose()?;
+
+        let extractor_errors = get_single_attr(
+            input
+                .attrs
+                .clone(),
+            "extractor_errors",
+        )?
+        .map(|attr| parse_extractor_errors(&attr))
+        .transpose()?
+        .unwrap_or_default();
+
+        let extractor_fallback = get_single_attr(
+            input
+                .attrs
+                .clone(),
+            "extractor_fallback",
+        )?
+        .map(|attr| parse_extractor_fallback(&attr))
+        .transpose()?;
+
+        let reject_not_acceptable = get_single_attr(input.attrs, "not_acceptable")?
+            .map(|attr| parse_not_acceptable(&attr))
+            .transpose()?
+            .unwrap_or(false);
 
         let variants = input
             .variants
             .into_iter()
-            .map(|variant| {
-                Ok(ErrorResponseVariant {
-                    status_code: get_single_attr(
-                        variant
-                            .attrs
-                            .clone(),
-                        "status_code",
-                    )?
-                    .map(|attr| attr.parse_args::<StatusCode>())
-                    .transpose()?
-                    .map(StatusCode::into_inner),
-                    variant,
-                })
-            })
+            .map(parse_variant)
             .collect::<SynResult<Vec<_>>>()?;
 
         if variants.is_empty() {
@@ -137,10 +351,33 @@ impl Parse for ErrorResponse {
             ));
         }
 
+        let languages = collect_languages(&variants)?;
+
+        validate_jsonapi_sources(&response_formats, &variants)?;
+        validate_html_templates(
+            html_template.as_ref(),
+            transform_response.as_ref(),
+            &response_formats,
+            &variants,
+        )?;
+        validate_extractor_variants(
+            &enum_name,
+            &variants,
+            &extractor_errors,
+            extractor_fallback.as_ref(),
+        )?;
+
         Ok(Self {
             enum_name,
+            visibility,
             default_status_code,
             transform_response,
+            languages,
+            response_formats,
+            reject_not_acceptable,
+            html_template,
+            extractor_errors,
+            extractor_fallback,
             variants,
         })
     }
@@ -157,6 +394,54 @@ impl ErrorResponseVariant {
             .as_ref()
     }
 
+    /// **`ErrorResponseVariant.messages`**
+    ///
+    /// The localized messages declared for this variant
+    /// with `#[message(lang = "..", "..")]`.
+    #[inline]
+    pub fn messages(&self) -> &[LocalizedMessage] {
+        &self.messages
+    }
+
+    /// **`ErrorResponseVariant.exposed_fields`**
+    ///
+    /// The fields annotated with `#[expose]`, which are
+    /// serialized in the structured error envelope.
+    #[inline]
+    pub fn exposed_fields(&self) -> &[Member] {
+        &self.exposed_fields
+    }
+
+    /// **`ErrorResponseVariant.source_pointer`**
+    ///
+    /// The JSON:API `source.pointer` declared with
+    /// `#[source_pointer(..)]` if any.
+    #[inline]
+    pub const fn source_pointer(&self) -> Option<&LitStr> {
+        self.source_pointer
+            .as_ref()
+    }
+
+    /// **`ErrorResponseVariant.source_parameter`**
+    ///
+    /// The JSON:API `source.parameter` declared with
+    /// `#[source_parameter(..)]` if any.
+    #[inline]
+    pub const fn source_parameter(&self) -> Option<&LitStr> {
+        self.source_parameter
+            .as_ref()
+    }
+
+    /// **`ErrorResponseVariant.html_template`**
+    ///
+    /// The template declared with `#[html_template(..)]`
+    /// for this specific variant if any.
+    #[inline]
+    pub const fn html_template(&self) -> Option<&HtmlTemplate> {
+        self.html_template
+            .as_ref()
+    }
+
     /// **`ErrorResponseVariant.variant`**
     ///
     /// The original enum variant from the AST.
@@ -166,6 +451,753 @@ impl ErrorResponseVariant {
     }
 }
 
+/// **`parse_variant()`**
+///
+/// Parses the meta for a single enum variant.
+fn parse_variant(variant: EnumVariant) -> SynResult<ErrorResponseVariant> {
+    Ok(ErrorResponseVariant {
+        status_code: get_single_attr(
+            variant
+                .attrs
+                .clone(),
+            "status_code",
+        )?
+        .map(|attr| attr.parse_args::<StatusCode>())
+        .transpose()?
+        .map(StatusCode::into_inner),
+        messages: parse_messages(&variant.attrs)?,
+        exposed_fields: parse_exposed_fields(&variant)?,
+        source_pointer: parse_source_pointer(&variant.attrs)?,
+        source_parameter: get_single_attr(
+            variant
+                .attrs
+                .clone(),
+            "source_parameter",
+        )?
+        .map(|attr| attr.parse_args::<LitStr>())
+        .transpose()?,
+        html_template: get_single_attr(
+            variant
+                .attrs
+                .clone(),
+            "html_template",
+        )?
+        .map(|attr| attr.parse_args::<HtmlTemplate>())
+        .transpose()?,
+        variant,
+    })
+}
+
+/// **`parse_messages()`**
+///
+/// Parses every `#[message(..)]` attribute in a variant,
+/// failing if the same language is declared more than once.
+fn parse_messages(attrs: &[Attribute]) -> SynResult<Vec<LocalizedMessage>> {
+    let mut messages = Vec::<LocalizedMessage>::new();
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| {
+            attr.path()
+                .is_ident("message")
+        })
+    {
+        let message = attr.parse_args::<LocalizedMessage>()?;
+
+        if messages
+            .iter()
+            .any(|existing| existing.lang() == message.lang())
+        {
+            return Err(SynError::new_spanned(
+                &message.lang,
+                format!("A message for \"{}\" was already declared.", message.lang()),
+            ));
+        }
+
+        messages.push(message);
+    }
+
+    Ok(messages)
+}
+
+/// **`parse_exposed_fields()`**
+///
+/// Collects the members of every variant field
+/// annotated with `#[expose]`.
+fn parse_exposed_fields(variant: &EnumVariant) -> SynResult<Vec<Member>> {
+    let mut exposed_fields = Vec::new();
+
+    for (idx, field) in variant
+        .fields
+        .iter()
+        .enumerate()
+    {
+        let Some(attr) = get_single_attr(
+            field
+                .attrs
+                .clone(),
+            "expose",
+        )?
+        else {
+            continue;
+        };
+
+        attr.meta
+            .require_path_only()?;
+
+        exposed_fields.push(
+            field
+                .ident
+                .clone()
+                .map_or_else(|| Member::Unnamed(Index::from(idx)), Member::Named),
+        );
+    }
+
+    Ok(exposed_fields)
+}
+
+/// **`parse_source_pointer()`**
+///
+/// Parses the `#[source_pointer(..)]` attribute, failing
+/// if the value is not a JSON pointer.
+fn parse_source_pointer(attrs: &[Attribute]) -> SynResult<Option<LitStr>> {
+    let Some(pointer) = get_single_attr(attrs.to_vec(), "source_pointer")?
+        .map(|attr| attr.parse_args::<LitStr>())
+        .transpose()?
+    else {
+        return Ok(None);
+    };
+
+    if !pointer
+        .value()
+        .starts_with('/')
+    {
+        return Err(SynError::new_spanned(
+            pointer,
+            "Expected a JSON pointer such as \"/data/attributes/email\".",
+        ));
+    }
+
+    Ok(Some(pointer))
+}
+
+/// **`validate_jsonapi_sources()`**
+///
+/// Fails if any variant declares a JSON:API source
+/// while the `jsonapi` response format is not declared.
+fn validate_jsonapi_sources(
+    response_formats: &[ResponseFormat],
+    variants: &[ErrorResponseVariant],
+) -> SynResult<()> {
+    if response_formats
+        .iter()
+        .any(|format| format.name() == "jsonapi")
+    {
+        return Ok(());
+    }
+
+    match variants
+        .iter()
+        .find_map(|variant| {
+            variant
+                .source_pointer()
+                .or(variant.source_parameter())
+        }) {
+        Some(source) => Err(SynError::new_spanned(
+            source,
+            "JSON:API sources require #[response_format(jsonapi)] in the enum.",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// **`validate_extractor_variants()`**
+///
+/// Fails if the `#[extractor_errors(..)]` or
+/// `#[extractor_fallback(..)]` attributes name a
+/// variant the enum doesn't have.
+fn validate_extractor_variants(
+    enum_name: &Ident,
+    variants: &[ErrorResponseVariant],
+    extractor_errors: &[ExtractorError],
+    extractor_fallback: Option<&Expr>,
+) -> SynResult<()> {
+    let extractor_variants = extractor_errors
+        .iter()
+        .map(ExtractorError::variant)
+        .chain(extractor_fallback);
+
+    for ident in extractor_variants.filter_map(variant_ident) {
+        if variants
+            .iter()
+            .any(|variant| {
+                variant
+                    .variant()
+                    .ident
+                    == *ident
+            })
+        {
+            continue;
+        }
+
+        return Err(SynError::new_spanned(
+            ident,
+            format!(
+                "{ident} is not a variant of {enum_name}, did you mean {}?",
+                closest_variant(
+                    variants
+                        .iter()
+                        .map(|variant| &variant
+                            .variant()
+                            .ident),
+                    &ident.to_string()
+                )
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// **`closest_variant()`**
+///
+/// Applies the edit distance algorithm to a typoed
+/// variant identifier and returns the most-likely
+/// to be variant of the enum.
+fn closest_variant<'a>(
+    variants: impl IntoIterator<Item = &'a Ident>,
+    target_variant: &str,
+) -> String {
+    variants
+        .into_iter()
+        .map(ToString::to_string)
+        .min_by_key(|variant| levenshtein(variant, target_variant))
+        .unwrap_or_default()
+}
+
+/// **`validate_html_templates()`**
+///
+/// Fails if templates are combined with a response
+/// transformer or formats without `html`, or if the
+/// template used by any variant references a field
+/// the variant doesn't have.
+fn validate_html_templates(
+    html_template: Option<&HtmlTemplate>,
+    transform_response: Option<&Ident>,
+    response_formats: &[ResponseFormat],
+    variants: &[ErrorResponseVariant],
+) -> SynResult<()> {
+    for variant in variants {
+        let Some(template) = variant
+            .html_template()
+            .or(html_template)
+        else {
+            continue;
+        };
+
+        if transform_response.is_some() {
+            return Err(SynError::new_spanned(
+                template.path(),
+                "html_template can't be used together with transform_response.",
+            ));
+        }
+
+        if !response_formats.is_empty()
+            && !response_formats
+                .iter()
+                .any(|format| format.name() == "html")
+        {
+            return Err(SynError::new_spanned(
+                template.path(),
+                "html_template is never rendered as html is not declared in \
+                 #[response_format(..)].",
+            ));
+        }
+
+        let fields = &variant
+            .variant()
+            .fields;
+
+        for name in template.field_placeholders() {
+            let exists = fields
+                .iter()
+                .enumerate()
+                .any(|(idx, field)| {
+                    field
+                        .ident
+                        .as_ref()
+                        .map_or_else(|| idx.to_string() == name, |ident| ident == name)
+                });
+
+            if !exists {
+                return Err(SynError::new_spanned(
+                    template.path(),
+                    format!(
+                        "The template references an unknown field \"{name}\" in the {} variant.",
+                        variant
+                            .variant()
+                            .ident
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// **`collect_languages()`**
+///
+/// Collects every language declared with `#[message(..)]`
+/// in declaration order, failing if any variant is missing
+/// a translation for any of them.
+fn collect_languages(variants: &[ErrorResponseVariant]) -> SynResult<Vec<String>> {
+    let languages = variants
+        .iter()
+        .flat_map(ErrorResponseVariant::messages)
+        .map(LocalizedMessage::lang)
+        .fold(Vec::new(), |mut acc, lang| {
+            if !acc.contains(&lang) {
+                acc.push(lang);
+            }
+
+            acc
+        });
+
+    for variant in variants {
+        let missing = languages
+            .iter()
+            .filter(|lang| {
+                !variant
+                    .messages()
+                    .iter()
+                    .any(|message| message.lang() == **lang)
+            })
+            .map(|lang| format!("\"{lang}\""))
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            return Err(SynError::new_spanned(
+                &variant
+                    .variant()
+                    .ident,
+                format!(
+                    "Missing #[message(..)] translations for {} in this variant.",
+                    missing.join(", ")
+                ),
+            ));
+        }
+    }
+
+    Ok(languages)
+}
+
+/// **`parse_not_acceptable()`**
+///
+/// Parses the `#[not_acceptable(..)]` attribute policy,
+/// returning whether not acceptable requests should be rejected.
+fn parse_not_acceptable(attr: &Attribute) -> SynResult<bool> {
+    let policy = attr.parse_args::<Ident>()?;
+
+    match policy
+        .to_string()
+        .as_str()
+    {
+        "reject" => Ok(true),
+        "default" => Ok(false),
+        _ => Err(SynError::new_spanned(
+            policy,
+            "Expected either reject or default as the not acceptable policy.",
+        )),
+    }
+}
+
+/// **`parse_response_formats()`**
+///
+/// Parses the `#[response_format(..)]` attribute arguments,
+/// failing if there are no formats, any is repeated or
+/// the enum also declares a response transformer.
+fn parse_response_formats(
+    attr: &Attribute,
+    transform_response: Option<&Ident>,
+) -> SynResult<Vec<ResponseFormat>> {
+    if transform_response.is_some() {
+        return Err(SynError::new_spanned(
+            attr,
+            "response_format can't be used together with transform_response.",
+        ));
+    }
+
+    let formats = attr
+        .parse_args_with(Punctuated::<ResponseFormat, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if formats.is_empty() {
+        return Err(SynError::new_spanned(attr, "Expected at least one response format."));
+    }
+
+    for (idx, format) in formats
+        .iter()
+        .enumerate()
+    {
+        if formats[..idx]
+            .iter()
+            .any(|previous| previous.0 == format.0)
+        {
+            return Err(SynError::new_spanned(
+                &format.0,
+                format!("The {} response format was already declared.", format.0),
+            ));
+        }
+    }
+
+    Ok(formats)
+}
+
+/// **`parse_extractor_errors()`**
+///
+/// Parses the `#[extractor_errors(..)]` attribute arguments,
+/// failing if there are no pairs or any extractor is unknown
+/// or repeated.
+fn parse_extractor_errors(attr: &Attribute) -> SynResult<Vec<ExtractorError>> {
+    let extractor_errors = attr
+        .parse_args_with(Punctuated::<ExtractorError, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if extractor_errors.is_empty() {
+        return Err(SynError::new_spanned(
+            attr,
+            "Expected at least one extractor, example: #[extractor_errors(Json => InvalidBody)].",
+        ));
+    }
+
+    for (idx, extractor_error) in extractor_errors
+        .iter()
+        .enumerate()
+    {
+        let extractor = extractor_error
+            .extractor
+            .to_string();
+
+        if !EXTRACTOR_KEYS.contains(&extractor.as_str()) {
+            let closest = EXTRACTOR_KEYS
+                .iter()
+                .min_by_key(|key| levenshtein(key, &extractor))
+                .unwrap_or(&EXTRACTOR_KEYS[0]);
+
+            return Err(SynError::new_spanned(
+                &extractor_error.extractor,
+                format!(
+                    "{extractor} is not a mappable extractor, did you mean {closest}? expected \
+                     any of {}.",
+                    EXTRACTOR_KEYS.join(", ")
+                ),
+            ));
+        }
+
+        if extractor_errors[..idx]
+            .iter()
+            .any(|previous| previous.extractor == extractor_error.extractor)
+        {
+            return Err(SynError::new_spanned(
+                &extractor_error.extractor,
+                format!("The {} extractor was already declared.", extractor_error.extractor),
+            ));
+        }
+    }
+
+    Ok(extractor_errors)
+}
+
+/// **`parse_extractor_fallback()`**
+///
+/// Parses the `#[extractor_fallback(..)]` attribute argument
+/// as the variant expression.
+fn parse_extractor_fallback(attr: &Attribute) -> SynResult<Expr> {
+    attr.parse_args::<Expr>()
+        .map_err(|err| SynError::new(err.span(), "Expected a variant expression."))
+}
+
+impl ExtractorError {
+    /// **`ExtractorError.extractor`**
+    ///
+    /// The extractor type name, matched against the last
+    /// segment of the handler parameter types.
+    #[inline]
+    pub fn extractor(&self) -> &Ident {
+        &self.extractor
+    }
+
+    /// **`ExtractorError.variant`**
+    ///
+    /// The variant expression the extractor errors are
+    /// rendered as, which may use the `err` and `req` bindings.
+    #[inline]
+    pub fn variant(&self) -> &Expr {
+        &self.variant
+    }
+}
+
+impl Parse for ExtractorError {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let extractor = input.parse::<Ident>()?;
+        input.parse::<Token![=>]>()?;
+        let variant = input
+            .parse::<Expr>()
+            .map_err(|err| SynError::new(err.span(), "Expected a variant expression."))?;
+
+        Ok(Self { extractor, variant })
+    }
+}
+
+impl ResolvedVariant {
+    /// **`ResolvedVariant.error`**
+    ///
+    /// The error type the variant is constructed from.
+    #[inline]
+    pub fn error(&self) -> &Type {
+        &self.error
+    }
+
+    /// **`ResolvedVariant.variant`**
+    ///
+    /// The variant expression, which names an
+    /// existing variant of the error type.
+    #[inline]
+    pub fn variant(&self) -> &Expr {
+        &self.variant
+    }
+}
+
+impl Parse for ResolvedVariant {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let variants;
+        bracketed!(variants in input);
+        let variants = Punctuated::<Ident, Token![,]>::parse_terminated(&variants)?;
+        let error = input.parse::<Type>()?;
+        input.parse::<Token![=>]>()?;
+        let variant = input.parse::<Expr>()?;
+
+        if let Some(ident) = variant_ident(&variant)
+            && !variants
+                .iter()
+                .any(|variant| variant == ident)
+        {
+            let enum_name = match &error {
+                Type::Path(TypePath { path, .. }) => path
+                    .segments
+                    .last()
+                    .map_or_else(String::new, |segment| {
+                        segment
+                            .ident
+                            .to_string()
+                    }),
+                _ => String::new(),
+            };
+
+            return Err(SynError::new_spanned(
+                ident,
+                format!(
+                    "{ident} is not a variant of {enum_name}, did you mean {}?",
+                    closest_variant(&variants, &ident.to_string())
+                ),
+            ));
+        }
+
+        Ok(Self { error, variant })
+    }
+}
+
+impl LocalizedMessage {
+    /// **`LocalizedMessage.lang`**
+    ///
+    /// The language tag this message is written in.
+    #[inline]
+    pub fn lang(&self) -> String {
+        self.lang
+            .value()
+    }
+
+    /// **`LocalizedMessage.format`**
+    ///
+    /// The format string literal for this message, which
+    /// may reference the variant fields like `thiserror` does.
+    #[inline]
+    pub fn format(&self) -> &LitStr {
+        &self.format
+    }
+}
+
+impl Parse for LocalizedMessage {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let key = input.parse::<Ident>()?;
+
+        if key != "lang" {
+            return Err(SynError::new_spanned(
+                key,
+                "Expected a language, example: #[message(lang = \"es\", \"..\")].",
+            ));
+        }
+
+        input.parse::<Token![=]>()?;
+        let lang = input.parse::<LitStr>()?;
+
+        if lang
+            .value()
+            .is_empty()
+            || !lang
+                .value()
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return Err(SynError::new_spanned(
+                lang,
+                "Expected a language tag such as \"es\" or \"ca-ES\".",
+            ));
+        }
+
+        input.parse::<Token![,]>()?;
+        let format = input.parse::<LitStr>()?;
+
+        if !input.is_empty() {
+            return Err(input.error("Unexpected tokens after the message."));
+        }
+
+        Ok(Self { lang, format })
+    }
+}
+
+impl HtmlTemplate {
+    /// **`HtmlTemplate.path`**
+    ///
+    /// The template path literal as written in the attribute,
+    /// relative to `CARGO_MANIFEST_DIR`.
+    #[inline]
+    pub fn path(&self) -> &LitStr {
+        &self.path
+    }
+
+    /// **`HtmlTemplate.file`**
+    ///
+    /// The resolved template path.
+    #[inline]
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// **`HtmlTemplate.segments`**
+    ///
+    /// The literal text and placeholders of the template.
+    #[inline]
+    pub fn segments(&self) -> &[TemplateSegment] {
+        &self.segments
+    }
+
+    /// **`HtmlTemplate.field_placeholders`**
+    ///
+    /// The placeholders that reference variant fields,
+    /// which are all of them but `message` and `status`.
+    pub fn field_placeholders(&self) -> impl Iterator<Item = &str> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                TemplateSegment::Placeholder(name)
+                    if !TEMPLATE_BUILTINS.contains(&name.as_str()) =>
+                {
+                    Some(name.as_str())
+                },
+                _ => None,
+            })
+    }
+}
+
+impl Parse for HtmlTemplate {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = input.parse::<LitStr>()?;
+
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+            SynError::new_spanned(
+                &path,
+                "Couldn't resolve CARGO_MANIFEST_DIR to find the template.",
+            )
+        })?;
+        let file = Path::new(&manifest_dir).join(path.value());
+
+        let contents = fs::read_to_string(&file).map_err(|error| {
+            SynError::new_spanned(
+                &path,
+                format!("Couldn't read the template at {}: {error}.", file.display()),
+            )
+        })?;
+
+        let segments =
+            parse_template(&contents).map_err(|error| SynError::new_spanned(&path, error))?;
+
+        Ok(Self {
+            file: file
+                .to_string_lossy()
+                .into_owned(),
+            path,
+            segments,
+        })
+    }
+}
+
+impl ResponseFormat {
+    /// **`ResponseFormat.name`**
+    ///
+    /// The response format identifier.
+    #[inline]
+    pub fn name(&self) -> String {
+        self.0
+            .to_string()
+    }
+
+    /// **`ResponseFormat.content_type`**
+    ///
+    /// The content type the error body is sent with
+    /// when this format is used.
+    #[inline]
+    pub fn content_type(&self) -> &'static str {
+        format_content_type(&self.name()).unwrap_or_else(|| unreachable!())
+    }
+}
+
+impl Parse for ResponseFormat {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        let ident_string = ident.to_string();
+
+        if format_content_type(&ident_string).is_some() {
+            Ok(ResponseFormat(ident))
+        } else if let Some(feature) = format_feature(&ident_string) {
+            Err(SynError::new_spanned(
+                ident,
+                format!(
+                    "The {ident_string} response format requires the \"{feature}\" feature of \
+                     actix_failwrap to be enabled."
+                ),
+            ))
+        } else {
+            Err(SynError::new_spanned(
+                ident,
+                format!(
+                    "{ident_string} is not a valid response format, did you mean {}? The allowed \
+                     formats are: {}",
+                    closest_format(&ident_string),
+                    allowed_formats()
+                        .iter()
+                        .map(|(format, _)| *format)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ))
+        }
+    }
+}
+
 impl StatusCode {
     /// **`StatusCode.into_inner()`**
     ///
@@ -219,7 +1251,7 @@ impl Parse for StatusCode {
                             "Only HTTP error statuses are allowed. ",
                             "{} is not a valid status code, did you mean {}?"
                         ),
-                        &ident_string,
+                        ident_string,
                         closest_status(&ident_string)
                     ),
                 ))
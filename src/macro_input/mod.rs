@@ -4,4 +4,6 @@
 //! for each exported macro parsing meta structures.
 
 pub mod error_response;
+pub mod proof_controller;
 pub mod proof_route;
+pub mod proof_scope;
@@ -0,0 +1,314 @@
+//! [`proof_controller`] Input Module
+//!
+//! Declares meta structs that implement
+//! parsing methods for the [`proof_controller`]
+//! macro.
+//!
+//! [`proof_controller`]: crate::proof_controller
+
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{
+    Error as SynError,
+    Ident,
+    ImplItem,
+    ImplItemFn,
+    ItemFn,
+    ItemImpl,
+    LitBool,
+    LitStr,
+    Receiver,
+    Result as SynResult,
+    Token,
+    Type,
+    TypePath,
+};
+
+use crate::helpers::macro_attrs::is_macro_attr;
+use crate::helpers::path_templates::parse_path_template;
+use crate::macro_input::proof_route::{
+    ProofRouteBody,
+    ProofRouteMeta,
+    parse_auto_register,
+    strip_parameter_attributes,
+};
+
+/// **`ProofControllerMeta`**
+///
+/// The path prefix every controller route is
+/// registered under.
+#[derive(Debug)]
+pub struct ProofControllerMeta {
+    prefix: String,
+    prefix_segments: Vec<String>, // the dynamic segment names in order.
+    auto_register: Option<bool>,  // submitted to the route registry if true.
+}
+
+/// **`ProofControllerBody`**
+///
+/// The controller `impl` block metadata.
+#[derive(Debug)]
+pub struct ProofControllerBody {
+    routes: Vec<(ProofRouteMeta, ProofRouteBody)>,
+    item_impl: ItemImpl, // without the proof_route attributes.
+}
+
+impl ProofControllerMeta {
+    /// **`ProofControllerMeta.prefix`**
+    ///
+    /// The path prefix for the controller routes.
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// **`ProofControllerMeta.prefix_segments`**
+    ///
+    /// The names of the dynamic segments declared
+    /// by the prefix in order.
+    #[inline]
+    pub fn prefix_segments(&self) -> &[String] {
+        &self.prefix_segments
+    }
+
+    /// **`ProofControllerMeta.auto_register`**
+    ///
+    /// The `auto_register = ..` option if declared, controllers are
+    /// only submitted to the route registry when it's true.
+    #[inline]
+    pub const fn auto_register(&self) -> Option<bool> {
+        self.auto_register
+    }
+}
+
+impl Parse for ProofControllerMeta {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let prefix_lit = input.parse::<LitStr>()?;
+        let prefix = prefix_lit.value();
+        let prefix_segments =
+            parse_path_template(&prefix).map_err(|err| SynError::new_spanned(&prefix_lit, err))?;
+        let mut auto_register = None;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            match key
+                .to_string()
+                .as_str()
+            {
+                "auto_register" if auto_register.is_some() => {
+                    return Err(SynError::new_spanned(key, "auto_register was already declared."));
+                },
+                "auto_register" => {
+                    auto_register = Some(parse_auto_register(&input.parse::<LitBool>()?)?);
+                },
+                _ => {
+                    return Err(SynError::new_spanned(
+                        &key,
+                        format!(
+                            "{key} is not a valid proof_controller option, expected auto_register."
+                        ),
+                    ));
+                },
+            }
+        }
+
+        Ok(Self { prefix, prefix_segments, auto_register })
+    }
+}
+
+impl ProofControllerBody {
+    /// **`ProofControllerBody.self_ty`**
+    ///
+    /// The controller type the `impl` block is for.
+    #[inline]
+    pub fn self_ty(&self) -> &Type {
+        &self
+            .item_impl
+            .self_ty
+    }
+
+    /// **`ProofControllerBody.routes`**
+    ///
+    /// The parsed `#[proof_route(..)]` annotated methods.
+    #[inline]
+    pub fn routes(&self) -> &[(ProofRouteMeta, ProofRouteBody)] {
+        &self.routes
+    }
+
+    /// **`ProofControllerBody.item_impl`**
+    ///
+    /// The original `impl` block with the route attributes
+    /// removed from its methods.
+    #[inline]
+    pub fn item_impl(&self) -> &ItemImpl {
+        &self.item_impl
+    }
+
+    /// **`ProofControllerBody.validate_paths()`**
+    ///
+    /// Checks the `web::Path` extractors and `#[path]` parameters
+    /// of every route method against its path segments, including
+    /// the ones of the controller prefix.
+    pub fn validate_paths(&self, meta: &ProofControllerMeta) -> SynResult<()> {
+        self.routes
+            .iter()
+            .try_for_each(|(route_meta, body)| {
+                body.validate_path_extractors(route_meta, meta.prefix(), meta.prefix_segments())
+                    .and_then(|()| {
+                        body.validate_path_sources(
+                            route_meta,
+                            meta.prefix(),
+                            meta.prefix_segments(),
+                        )
+                    })
+            })
+    }
+}
+
+impl Parse for ProofControllerBody {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut item_impl = input.parse::<ItemImpl>()?;
+
+        if let Some(trait_) = &item_impl.trait_ {
+            return Err(SynError::new_spanned(
+                &trait_.1,
+                "proof_controller can only be applied to inherent impl blocks.",
+            ));
+        }
+
+        if !item_impl
+            .generics
+            .params
+            .is_empty()
+        {
+            return Err(SynError::new_spanned(
+                &item_impl.generics,
+                "proof_controller doesn't support generic impl blocks.",
+            ));
+        }
+
+        let mut routes = Vec::new();
+
+        for item in &mut item_impl.items {
+            let ImplItem::Fn(method) = item else {
+                continue;
+            };
+
+            if let Some(route) = parse_route_method(method)? {
+                routes.push(route);
+            }
+        }
+
+        Ok(Self { routes, item_impl })
+    }
+}
+
+/// **`parse_route_method()`**
+///
+/// Parses a controller method annotated with `#[proof_route(..)]`,
+/// removing the route and `error_override` attributes from it.
+/// Returns None if the method is not a route.
+fn parse_route_method(
+    method: &mut ImplItemFn,
+) -> SynResult<Option<(ProofRouteMeta, ProofRouteBody)>> {
+    let Some(position) = method
+        .attrs
+        .iter()
+        .position(|attr| is_macro_attr(attr, "proof_route"))
+    else {
+        return Ok(None);
+    };
+
+    let route_attr = method
+        .attrs
+        .remove(position);
+    let meta = route_attr.parse_args::<ProofRouteMeta>()?;
+
+    if meta
+        .expose_inner()
+        .is_some()
+    {
+        return Err(SynError::new_spanned(
+            route_attr,
+            "expose_inner is not needed in proof_controller, methods can be called directly.",
+        ));
+    }
+
+    if meta
+        .auto_register()
+        .is_some()
+    {
+        return Err(SynError::new_spanned(
+            route_attr,
+            "auto_register can't be declared per method, declare it in the proof_controller.",
+        ));
+    }
+
+    let function = ItemFn {
+        attrs: method
+            .attrs
+            .clone(),
+        vis: method
+            .vis
+            .clone(),
+        sig: method
+            .sig
+            .clone(),
+        block: Box::new(
+            method
+                .block
+                .clone(),
+        ),
+    };
+    let body = (|input: ParseStream| ProofRouteBody::parse_with_error(input, meta.error()))
+        .parse2(function.into_token_stream())?;
+
+    if body.is_generic() {
+        return Err(SynError::new_spanned(
+            &method
+                .sig
+                .generics,
+            "proof_controller doesn't support generic handlers.",
+        ));
+    }
+
+    if let Some(receiver) = body.receiver()
+        && !is_shared_self(receiver)
+    {
+        return Err(SynError::new_spanned(
+            receiver,
+            "Only &self and self: &Self receivers are supported, the controller is shared through \
+             web::Data.",
+        ));
+    }
+
+    strip_parameter_attributes(&mut method.sig);
+
+    Ok(Some((meta, body)))
+}
+
+/// **`is_shared_self()`**
+///
+/// Whether the receiver borrows the controller immutably,
+/// either written as `&self` or typed as `self: &Self`.
+fn is_shared_self(receiver: &Receiver) -> bool {
+    let Type::Reference(reference) = &*receiver.ty else {
+        return false;
+    };
+
+    reference
+        .mutability
+        .is_none()
+        && matches!(
+            &*reference.elem,
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("Self")
+        )
+}
@@ -6,27 +6,85 @@
 //!
 //! [`proof_route`]: crate::proof_route
 
+use std::mem::replace;
+
+use strsim::levenshtein;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::{
     Attribute,
     Error as SynError,
     Expr,
     FnArg,
+    GenericParam,
     Ident,
     ItemFn,
+    LitBool,
     LitStr,
+    Meta,
+    Pat,
+    PatIdent,
+    PatType,
+    Path,
+    Receiver,
     Result as SynResult,
     ReturnType,
+    Signature,
+    Token,
     Type,
+    Visibility,
 };
 
-use crate::helpers::semantics::has_result_semantics;
+use crate::helpers::http_methods::{HTTP_METHODS, normalize_method};
+use crate::helpers::path_templates::parse_path_template;
+use crate::helpers::semantics::{has_path_semantics, has_result_semantics, is_primitive_type};
+
+/// **`LINT_ATTRIBUTES`**
+///
+/// The attributes that configure lints, which apply
+/// both to the generated service and the handler.
+const LINT_ATTRIBUTES: [&str; 5] = ["allow", "warn", "deny", "forbid", "expect"];
+
+/// **`PARAMETER_ATTRIBUTES`**
+///
+/// The attributes parsed from the handler parameters,
+/// removed from the handler function.
+const PARAMETER_ATTRIBUTES: [&str; 6] =
+    ["error_override", "validate", "path", "query", "header", "cookie"];
+
+/// **`ERROR_KINDS`**
+///
+/// The kinds of `JsonPayloadError`, `UrlencodedError`,
+/// `QueryPayloadError` and `PathError` that can be matched
+/// in `#[error_override(Kind => Variant, ..)]`, including
+/// the [`DESERIALIZE_CATEGORIES`].
+pub const ERROR_KINDS: [&str; 12] = [
+    "ContentType",
+    "Overflow",
+    "UnknownLength",
+    "Chunked",
+    "Encoding",
+    "Parse",
+    "Deserialize",
+    "Serialize",
+    "Payload",
+    "Syntax",
+    "Data",
+    "Eof",
+];
 
-/// **`HTTP_METHODS`**
+/// **`DESERIALIZE_CATEGORIES`**
 ///
-/// An array of HTTP method string literals.
-const HTTP_METHODS: [&str; 9] =
-    ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS", "CONNECT", "TRACE"];
+/// The kinds narrowing down a `JsonPayloadError` failing to
+/// deserialize by its `serde_json` category, which are also
+/// matched by the `Deserialize` kind.
+pub const DESERIALIZE_CATEGORIES: [&str; 3] = ["Syntax", "Data", "Eof"];
+
+/// **`SERVICE_ATTRIBUTES`**
+///
+/// The attributes that only apply to the generated
+/// service, the rest are kept in the handler.
+const SERVICE_ATTRIBUTES: [&str; 4] = ["doc", "cfg", "cfg_attr", "deprecated"];
 
 /// **`ProofRouteMeta`**
 ///
@@ -34,8 +92,28 @@ const HTTP_METHODS: [&str; 9] =
 /// wrapper.
 #[derive(Debug)]
 pub struct ProofRouteMeta {
-    method: String,
+    routes: Vec<RouteDeclaration>,
+    expose_inner: Option<Ident>, // the handler is nested in the service if None.
+    error: Option<Type>,         // the handler error type for Result aliases.
+    auto_register: Option<bool>, // submitted to the route registry if true.
+    name: Option<LitStr>,        // the resource name, the handler name if None.
+    guards: Vec<Path>,           // functions wrapped with `guard::fn_guard`.
+    wraps: Vec<Expr>,            // middleware wrapping the resource.
+    extractor_error: Option<ErrorOverride>, // for extractors without an override.
+    path_to_error: bool,         // payloads deserialized with `serde_path_to_error` if true.
+    scope_segments: Option<Vec<String>>, // the dynamic segments of the enclosing scopes.
+}
+
+/// **`RouteDeclaration`**
+///
+/// A `"METHOD|METHOD /path"` declaration, the handler
+/// is routed for each of the methods in the path.
+#[derive(Debug)]
+pub struct RouteDeclaration {
+    methods: Vec<String>, // normalized, without duplicates.
     path: String,
+    segments: Vec<String>, // the dynamic segment names in order.
+    lit_str: LitStr,
 }
 
 /// **`ProofRouteBody`**
@@ -44,8 +122,11 @@ pub struct ProofRouteMeta {
 #[derive(Debug)]
 pub struct ProofRouteBody {
     name: Ident,
+    vis: Visibility,
+    attributes: Vec<Attribute>, // forwarded to the generated service.
+    receiver: Option<Receiver>, // only allowed in proof_controller methods.
     parameters: Vec<ProofRouteParameter>,
-    return_result_semantics: (Type, Type),
+    return_error: Type,
     function: ItemFn,
 }
 
@@ -54,61 +135,482 @@ pub struct ProofRouteBody {
 /// [`ProofRouteBody`] function input metadata.
 #[derive(Debug)]
 pub struct ProofRouteParameter {
-    error_override: Option<Expr>,
+    error_override: Option<ErrorOverride>,
+    validation: Option<Validation>, // run after the extraction if declared.
+    source: Option<ParameterSource>, // extracted by the wrapper if declared.
     ty: Type,
 }
 
+/// **`ErrorOverride`**
+///
+/// The `#[error_override(..)]` of a parameter, either a
+/// variant expression of the handler error, which may use
+/// the `err` and `req` bindings, `with = function` mapping
+/// them into the handler error or `Kind => Variant` arms.
+#[derive(Debug)]
+pub enum ErrorOverride {
+    Variant(Expr),
+    With(Expr),
+    Kinds(Vec<ErrorKindArm>),
+}
+
+/// **`ErrorKindArm`**
+///
+/// A `Kind | Kind => Variant` arm of a kind specific
+/// `#[error_override(..)]`, matching any kind if empty.
+#[derive(Debug)]
+pub struct ErrorKindArm {
+    kinds: Vec<Ident>,
+    variant: Expr,
+}
+
+/// **`Validation`**
+///
+/// The `#[validate(..)]` of a parameter, which is validated
+/// with the backend after the extraction, rendering failures
+/// as the `on_error` variant if declared.
+#[derive(Debug)]
+pub struct Validation {
+    backend: ValidationBackend,
+    on_error: Option<Expr>,
+}
+
+/// **`ValidationBackend`**
+///
+/// The crate parameters are validated with, each
+/// enabled by the feature of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationBackend {
+    Validator,
+    Garde,
+}
+
+/// **`ParameterSource`**
+///
+/// Where a parameter annotated with `#[path]`, `#[query]`,
+/// `#[header(..)]` or `#[cookie(..)]` is extracted from,
+/// alongside the name it is looked up by.
+#[derive(Debug)]
+pub enum ParameterSource {
+    Path(String),
+    Query(String),
+    Header(String),
+    Cookie(String),
+}
+
 impl ProofRouteMeta {
-    /// **`ProofRouteMeta.method`**
+    /// **`ProofRouteMeta.routes`**
+    ///
+    /// The route declarations for this handler,
+    /// in the order they were declared.
+    #[inline]
+    pub fn routes(&self) -> &[RouteDeclaration] {
+        &self.routes
+    }
+
+    /// **`ProofRouteMeta.method_paths`**
+    ///
+    /// Every method and path pair the handler is routed for.
+    pub fn method_paths(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.routes
+            .iter()
+            .flat_map(|route| {
+                route
+                    .methods
+                    .iter()
+                    .map(|method| {
+                        (
+                            method.as_str(),
+                            route
+                                .path
+                                .as_str(),
+                        )
+                    })
+            })
+    }
+
+    /// **`ProofRouteMeta.expose_inner`**
     ///
-    /// The HTTP status method compatible as an
-    /// `actix_web` method attribute macro.
+    /// The name the original handler function is exposed
+    /// with if `expose_inner = ".."` was declared.
     #[inline]
-    pub fn method(&self) -> &str {
-        &self.method
+    pub const fn expose_inner(&self) -> Option<&Ident> {
+        self.expose_inner
+            .as_ref()
     }
 
-    /// **`ProofRouteMeta.path`**
+    /// **`ProofRouteMeta.error`**
     ///
-    /// The HTTP path for this handler.
+    /// The handler error type if declared with `error = ..`,
+    /// required when the return type is a `Result` alias.
     #[inline]
-    pub fn path(&self) -> &str {
-        &self.path
+    pub const fn error(&self) -> Option<&Type> {
+        self.error
+            .as_ref()
+    }
+
+    /// **`ProofRouteMeta.auto_register`**
+    ///
+    /// The `auto_register = ..` option if declared, routes are
+    /// only submitted to the route registry when it's true.
+    #[inline]
+    pub const fn auto_register(&self) -> Option<bool> {
+        self.auto_register
+    }
+
+    /// **`ProofRouteMeta.name`**
+    ///
+    /// The resource name declared with `name = ".."`
+    /// to generate URLs with `HttpRequest::url_for`.
+    #[inline]
+    pub const fn name(&self) -> Option<&LitStr> {
+        self.name
+            .as_ref()
+    }
+
+    /// **`ProofRouteMeta.guards`**
+    ///
+    /// The guard functions declared with `guard = ".."`
+    /// in the order they were declared.
+    #[inline]
+    pub fn guards(&self) -> &[Path] {
+        &self.guards
+    }
+
+    /// **`ProofRouteMeta.wraps`**
+    ///
+    /// The middleware expressions declared with `wrap = ".."`
+    /// in the order they were declared.
+    #[inline]
+    pub fn wraps(&self) -> &[Expr] {
+        &self.wraps
+    }
+
+    /// **`ProofRouteMeta.extractor_error`**
+    ///
+    /// The variant declared with `extractor_error = ..` that
+    /// replaces the errors of the extractors without their
+    /// own `#[error_override(..)]`.
+    #[inline]
+    pub const fn extractor_error(&self) -> Option<&ErrorOverride> {
+        self.extractor_error
+            .as_ref()
+    }
+
+    /// **`ProofRouteMeta.path_to_error`**
+    ///
+    /// Whether `path_to_error = true` was declared, meaning the
+    /// `Json`, `Query` and `Form` extractors are deserialized
+    /// with `serde_path_to_error`.
+    #[inline]
+    pub const fn path_to_error(&self) -> bool {
+        self.path_to_error
+    }
+
+    /// **`ProofRouteMeta.scope_segments`**
+    ///
+    /// The dynamic segments of the `scope_prefix = ".."`
+    /// declared for the enclosing scopes if any, which
+    /// `#[path]` parameters can also be extracted from.
+    #[inline]
+    pub fn scope_segments(&self) -> Option<&[String]> {
+        self.scope_segments
+            .as_deref()
+    }
+
+    /// **`ProofRouteMeta.full_segments()`**
+    ///
+    /// The dynamic segments a route declaration is matched
+    /// with, the ones of the `scope_prefix`, the provided
+    /// prefix and the route path in order.
+    pub fn full_segments<'a>(
+        &'a self,
+        prefix_segments: &'a [String],
+        route: &'a RouteDeclaration,
+    ) -> Vec<&'a String> {
+        self.scope_segments()
+            .unwrap_or_default()
+            .iter()
+            .chain(prefix_segments)
+            .chain(route.segments())
+            .collect()
     }
 }
 
 impl Parse for ProofRouteMeta {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let lit_str = input.parse::<LitStr>()?;
+        let mut routes = vec![parse_route_declaration(&input.parse::<LitStr>()?)?];
+        let mut expose_inner = None;
+        let mut error = None;
+        let mut auto_register = None;
+        let mut name = None;
+        let mut guards = Vec::new();
+        let mut wraps = Vec::new();
+        let mut extractor_error = None;
+        let mut path_to_error = None;
+        let mut scope_segments = None;
 
-        let Some((method, path)) = lit_str
-            .value()
-            .split_once(' ')
-            .map(|(m, p)| (m.to_string(), p.to_string()))
-        else {
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+
+            if input.peek(LitStr) {
+                push_route_declaration(&mut routes, &input.parse::<LitStr>()?)?;
+                continue;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            match key
+                .to_string()
+                .as_str()
+            {
+                "expose_inner" if expose_inner.is_some() => {
+                    return Err(SynError::new_spanned(key, "expose_inner was already declared."));
+                },
+                "expose_inner" => expose_inner = Some(parse_expose_inner(&input.parse()?)?),
+                "error" if error.is_some() => {
+                    return Err(SynError::new_spanned(key, "error was already declared."));
+                },
+                "error" => error = Some(input.parse::<Type>()?),
+                "auto_register" if auto_register.is_some() => {
+                    return Err(SynError::new_spanned(key, "auto_register was already declared."));
+                },
+                "auto_register" => {
+                    auto_register = Some(parse_auto_register(&input.parse::<LitBool>()?)?);
+                },
+                "name" if name.is_some() => {
+                    return Err(SynError::new_spanned(key, "name was already declared."));
+                },
+                "name" => name = Some(input.parse::<LitStr>()?),
+                "guard" => guards.push(parse_lit_str_option(&input.parse::<LitStr>()?)?),
+                "wrap" => wraps.push(parse_lit_str_option(&input.parse::<LitStr>()?)?),
+                "extractor_error" if extractor_error.is_some() => {
+                    return Err(SynError::new_spanned(
+                        key,
+                        "extractor_error was already declared.",
+                    ));
+                },
+                "extractor_error" => {
+                    extractor_error = Some(ErrorOverride::Variant(input.parse::<Expr>()?));
+                },
+                "path_to_error" if path_to_error.is_some() => {
+                    return Err(SynError::new_spanned(key, "path_to_error was already declared."));
+                },
+                "path_to_error" => path_to_error = Some(parse_path_to_error(&input.parse()?)?),
+                "scope_prefix" if scope_segments.is_some() => {
+                    return Err(SynError::new_spanned(key, "scope_prefix was already declared."));
+                },
+                "scope_prefix" => scope_segments = Some(parse_scope_prefix(&input.parse()?)?),
+                _ => {
+                    return Err(SynError::new_spanned(
+                        &key,
+                        format!(
+                            "{key} is not a valid proof_route option, expected any of \
+                             expose_inner, error, auto_register, name, guard, wrap, \
+                             extractor_error, path_to_error, scope_prefix."
+                        ),
+                    ));
+                },
+            }
+        }
+
+        Ok(Self {
+            routes,
+            expose_inner,
+            error,
+            auto_register,
+            name,
+            guards,
+            wraps,
+            extractor_error,
+            path_to_error: path_to_error.unwrap_or(false),
+            scope_segments,
+        })
+    }
+}
+
+/// **`parse_auto_register()`**
+///
+/// Parses the `auto_register = ..` option, failing if it's
+/// enabled without the `auto-register` feature.
+pub fn parse_auto_register(value: &LitBool) -> SynResult<bool> {
+    if value.value && !cfg!(feature = "auto-register") {
+        return Err(SynError::new_spanned(
+            value,
+            "auto_register requires the \"auto-register\" feature of actix_failwrap to be enabled.",
+        ));
+    }
+
+    Ok(value.value)
+}
+
+/// **`parse_expose_inner()`**
+///
+/// Parses the `expose_inner = ".."` option as the
+/// name of the exposed handler function.
+fn parse_expose_inner(name: &LitStr) -> SynResult<Ident> {
+    name.parse::<Ident>()
+        .map_err(|_| SynError::new_spanned(name, "Expected a valid function name."))
+}
+
+/// **`parse_scope_prefix()`**
+///
+/// Parses the `scope_prefix = ".."` option as the path
+/// template of the enclosing scopes, returning the names
+/// of its dynamic segments.
+fn parse_scope_prefix(prefix: &LitStr) -> SynResult<Vec<String>> {
+    parse_path_template(&prefix.value()).map_err(|err| SynError::new_spanned(prefix, err))
+}
+
+/// **`parse_path_to_error()`**
+///
+/// Parses the `path_to_error = ..` option, failing if it's
+/// enabled without the `serde_path_to_error` feature.
+fn parse_path_to_error(value: &LitBool) -> SynResult<bool> {
+    if value.value && !cfg!(feature = "serde_path_to_error") {
+        return Err(SynError::new_spanned(
+            value,
+            "path_to_error requires the \"serde_path_to_error\" feature of actix_failwrap to be \
+             enabled.",
+        ));
+    }
+
+    Ok(value.value)
+}
+
+/// **`push_route_declaration()`**
+///
+/// Parses a further route declaration, failing if any
+/// of its methods was already declared for the path.
+fn push_route_declaration(routes: &mut Vec<RouteDeclaration>, lit_str: &LitStr) -> SynResult<()> {
+    let route = parse_route_declaration(lit_str)?;
+
+    if let Some(method) = route
+        .methods
+        .iter()
+        .find(|method| {
+            routes
+                .iter()
+                .any(|declared| {
+                    declared.path == route.path
+                        && declared
+                            .methods
+                            .contains(method)
+                })
+        })
+    {
+        return Err(SynError::new_spanned(
+            lit_str,
+            format!("{method} {} was already declared.", route.path),
+        ));
+    }
+
+    routes.push(route);
+
+    Ok(())
+}
+
+/// **`parse_lit_str_option()`**
+///
+/// Parses the contents of a string literal option such as
+/// `guard = "is_json"`, matching the `actix_web` route macros.
+fn parse_lit_str_option<T: Parse>(lit_str: &LitStr) -> SynResult<T> {
+    lit_str
+        .parse::<T>()
+        .map_err(|err| SynError::new_spanned(lit_str, format!("Invalid option value, {err}.")))
+}
+
+/// **`parse_route_declaration()`**
+///
+/// Parses a `"METHOD|METHOD /path"` route declaration,
+/// validating each method with [`normalize_method`].
+fn parse_route_declaration(lit_str: &LitStr) -> SynResult<RouteDeclaration> {
+    let Some((methods, path)) = lit_str
+        .value()
+        .split_once(' ')
+        .map(|(m, p)| (m.to_string(), p.to_string()))
+    else {
+        return Err(SynError::new_spanned(
+            lit_str,
+            concat!(
+                "Expected a space in between the method and the path,",
+                " example: \"<method> <path>\"."
+            ),
+        ));
+    };
+
+    let mut declared = Vec::new();
+
+    for method in methods.split('|') {
+        let Some(method) = normalize_method(method) else {
             return Err(SynError::new_spanned(
                 lit_str,
-                concat!(
-                    "Expected a space in between the method and the path,",
-                    " example: \"<method> <path>\"."
+                format!(
+                    "{method} is not a valid HTTP method, expected any of {} or a valid RFC 7230 \
+                     method token.",
+                    HTTP_METHODS.join(", ")
                 ),
             ));
         };
 
-        if !HTTP_METHODS
-            .iter()
-            .any(|available| available.eq_ignore_ascii_case(&method))
-        {
+        if declared.contains(&method) {
             return Err(SynError::new_spanned(
-                &method,
-                format!(
-                    "{method} is not a valid HTTP method, expected any of {}",
-                    HTTP_METHODS.join(", ")
-                ),
+                lit_str,
+                format!("{method} {path} was already declared."),
             ));
         }
 
-        Ok(Self { method, path })
+        declared.push(method);
+    }
+
+    let segments = parse_path_template(&path).map_err(|err| SynError::new_spanned(lit_str, err))?;
+
+    Ok(RouteDeclaration {
+        methods: declared,
+        path,
+        segments,
+        lit_str: lit_str.clone(),
+    })
+}
+
+impl RouteDeclaration {
+    /// **`RouteDeclaration.methods`**
+    ///
+    /// The HTTP methods, standard methods are uppercase
+    /// and extension methods are kept as declared.
+    #[inline]
+    pub fn methods(&self) -> &[String] {
+        &self.methods
+    }
+
+    /// **`RouteDeclaration.path`**
+    ///
+    /// The HTTP path for these methods.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// **`RouteDeclaration.segments`**
+    ///
+    /// The names of the path dynamic segments in order.
+    #[inline]
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// **`RouteDeclaration.lit_str`**
+    ///
+    /// The declaration literal, used to span
+    /// the errors related to its path.
+    #[inline]
+    pub fn lit_str(&self) -> &LitStr {
+        &self.lit_str
     }
 }
 
@@ -121,6 +623,61 @@ impl ProofRouteBody {
         &self.name
     }
 
+    /// **`ProofRouteBody.vis`**
+    ///
+    /// The handler function original visibility,
+    /// inherited by the generated service.
+    #[inline]
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+
+    /// **`ProofRouteBody.attributes`**
+    ///
+    /// The handler function doc comments, `cfg`, `deprecated`
+    /// and lint attributes, forwarded to the generated service.
+    #[inline]
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// **`ProofRouteBody.cfg_attributes`**
+    ///
+    /// The `cfg` attributes of the handler function, which
+    /// also gate the generated service implementation.
+    pub fn cfg_attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes
+            .iter()
+            .filter(|attr| {
+                attr.path()
+                    .is_ident("cfg")
+            })
+    }
+
+    /// **`ProofRouteBody.receiver`**
+    ///
+    /// The handler `self` receiver if any, which is
+    /// only valid for `proof_controller` methods.
+    #[inline]
+    pub const fn receiver(&self) -> Option<&Receiver> {
+        self.receiver
+            .as_ref()
+    }
+
+    /// **`ProofRouteBody.is_generic`**
+    ///
+    /// Whether the handler function declares type or const
+    /// parameters, in which case a service factory function
+    /// is generated instead of a service.
+    pub fn is_generic(&self) -> bool {
+        self.function
+            .sig
+            .generics
+            .params
+            .iter()
+            .any(|param| !matches!(param, GenericParam::Lifetime(_)))
+    }
+
     /// **`ProofRouteBody.parameters`**
     ///
     /// The handler function input parameters.
@@ -129,13 +686,14 @@ impl ProofRouteBody {
         &self.parameters
     }
 
-    /// **`ProofRouteBody.return_result_semantics`**
+    /// **`ProofRouteBody.return_error`**
     ///
-    /// The semantics ie. The output of [`has_result_semantics`]
-    /// applied to the return type of the function.
+    /// The error type of the handler, either declared with
+    /// `error = ..` or the `E` in the returned `Result<T, E>`
+    /// according to [`has_result_semantics`].
     #[inline]
-    pub fn return_result_semantics(&self) -> &(Type, Type) {
-        &self.return_result_semantics
+    pub fn return_error(&self) -> &Type {
+        &self.return_error
     }
 
     /// **`ProofRouteBody.function`**
@@ -148,8 +706,13 @@ impl ProofRouteBody {
     }
 }
 
-impl Parse for ProofRouteBody {
-    fn parse(input: ParseStream) -> SynResult<Self> {
+impl ProofRouteBody {
+    /// **`ProofRouteBody::parse_with_error()`**
+    ///
+    /// Parses the handler function, when the error type is
+    /// declared with `error = ..` the return type may be any
+    /// `Result` alias, otherwise a `Result<T, E>` is expected.
+    pub fn parse_with_error(input: ParseStream, error: Option<&Type>) -> SynResult<Self> {
         let mut function = input.parse::<ItemFn>()?;
 
         let name = function
@@ -176,31 +739,23 @@ impl Parse for ProofRouteBody {
             ));
         }
 
+        let attributes = split_service_attributes(&mut function);
+        let vis = replace(&mut function.vis, Visibility::Inherited);
+
+        let receiver = function
+            .sig
+            .receiver()
+            .cloned();
+
         let parameters = function
             .sig
             .inputs
-            .iter_mut()
+            .iter()
             .filter_map(|parameter| match parameter {
                 FnArg::Receiver(_) => None,
-                FnArg::Typed(typed) => Some(typed.clone()),
+                FnArg::Typed(typed) => Some(parse_parameter(typed)),
             })
-            .map(|parameter| {
-                Ok::<_, SynError>(ProofRouteParameter {
-                    error_override: parameter
-                        .attrs
-                        .iter()
-                        .find(|attribute| {
-                            attribute
-                                .path()
-                                .is_ident("error_override")
-                        })
-                        .map(Attribute::parse_args::<Expr>)
-                        .transpose()
-                        .map_err(|err| SynError::new(err.span(), "Expected an expression."))?,
-                    ty: *parameter.ty,
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+            .collect::<SynResult<Vec<_>>>()?;
 
         let output_match_err = SynError::new_spanned(
             &function
@@ -209,50 +764,644 @@ impl Parse for ProofRouteBody {
             "Expected a Result<T: actix_web::Responder, E: Into<actix_web::HttpResponse>>",
         );
 
-        let (r_t, r_e) = if let ReturnType::Type(_, ty) = &function
-            .sig
-            .output
-        {
-            has_result_semantics(ty).ok_or(output_match_err)?
-        } else {
-            return Err(output_match_err);
+        let return_error = match (
+            &function
+                .sig
+                .output,
+            error,
+        ) {
+            (ReturnType::Default, _) => return Err(output_match_err),
+            (ReturnType::Type(..), Some(error)) => error.clone(),
+            (ReturnType::Type(_, ty), None) => has_result_semantics(ty)
+                .ok_or(output_match_err)?
+                .1
+                .clone(),
         };
 
-        for mut input in &mut function
-            .sig
-            .inputs
-        {
-            if let FnArg::Typed(typed) = &mut input {
-                typed
-                    .attrs
-                    .retain(|attr| {
-                        !attr
-                            .path()
-                            .is_ident("error_override")
-                    });
-            }
-        }
+        strip_parameter_attributes(&mut function.sig);
 
         Ok(Self {
             name,
+            vis,
+            attributes,
+            receiver,
             parameters,
-            return_result_semantics: (r_t.clone(), r_e.clone()),
+            return_error,
             function,
         })
     }
 }
 
+impl ProofRouteBody {
+    /// **`ProofRouteBody.validate_path_extractors()`**
+    ///
+    /// Checks the `web::Path` extractors against the dynamic
+    /// segments of every route declaration, its `prefix` and
+    /// the declared `scope_prefix`. Primitives must be extracted
+    /// from a single segment and tuples can't take more values
+    /// than the declared segments, while the struct fields are
+    /// checked by the generated `path_field_checks`.
+    pub fn validate_path_extractors(
+        &self,
+        meta: &ProofRouteMeta,
+        prefix: &str,
+        prefix_segments: &[String],
+    ) -> SynResult<()> {
+        for parameter in &self.parameters {
+            let Some(inner) = has_path_semantics(parameter.ty()) else {
+                continue;
+            };
+
+            let expected = match inner {
+                Type::Tuple(tuple) => tuple
+                    .elems
+                    .len(),
+                inner if is_primitive_type(inner) => 1,
+                _ => continue,
+            };
+
+            for route in meta.routes() {
+                let segments = meta.full_segments(prefix_segments, route);
+
+                let problem = match inner {
+                    Type::Tuple(_) if expected > segments.len() => format!(
+                        "This extractor takes {expected} values but the path \"{prefix}{}\" only \
+                         declares {} segment(s)",
+                        route.path(),
+                        segments.len()
+                    ),
+                    Type::Tuple(_) => continue,
+                    _ if segments.is_empty() => format!(
+                        "This extractor takes a single value but the path \"{prefix}{}\" doesn't \
+                         declare any segment",
+                        route.path()
+                    ),
+                    _ if segments.len() > 1 => format!(
+                        "This extractor takes a single value but the path \"{prefix}{}\" declares \
+                         {} segments: {}",
+                        route.path(),
+                        segments.len(),
+                        segments
+                            .iter()
+                            .map(|segment| segment.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    _ => continue,
+                };
+
+                return Err(SynError::new_spanned(
+                    parameter.ty(),
+                    format!(
+                        "{problem}. Segments of enclosing scopes must be declared with \
+                         scope_prefix = \"..\"."
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// **`ProofRouteBody.validate_path_sources()`**
+    ///
+    /// Checks the names of the `#[path]` parameters against
+    /// the dynamic segments of every route declaration, its
+    /// `prefix` and the declared `scope_prefix`, as a missing
+    /// segment would respond 404 to every request.
+    pub fn validate_path_sources(
+        &self,
+        meta: &ProofRouteMeta,
+        prefix: &str,
+        prefix_segments: &[String],
+    ) -> SynResult<()> {
+        for parameter in &self.parameters {
+            let Some(ParameterSource::Path(name)) = parameter.source() else {
+                continue;
+            };
+
+            for route in meta.routes() {
+                let segments = meta.full_segments(prefix_segments, route);
+
+                if segments.contains(&name) {
+                    continue;
+                }
+
+                let suggestion = segments
+                    .iter()
+                    .min_by_key(|segment| levenshtein(segment, name))
+                    .map(|segment| format!(" Did you mean {segment}?"))
+                    .unwrap_or_default();
+
+                return Err(SynError::new_spanned(
+                    parameter.ty(),
+                    format!(
+                        "The path \"{prefix}{}\" doesn't declare the {name} segment.{suggestion} \
+                         Segments of enclosing scopes must be declared with scope_prefix = \"..\".",
+                        route.path()
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Parse for ProofRouteBody {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        Self::parse_with_error(input, None)
+    }
+}
+
+/// **`split_service_attributes()`**
+///
+/// Takes the attributes that apply to the generated service
+/// from the handler function, lint attributes are kept in
+/// both of them.
+fn split_service_attributes(function: &mut ItemFn) -> Vec<Attribute> {
+    let attributes = function
+        .attrs
+        .iter()
+        .filter(|attr| {
+            SERVICE_ATTRIBUTES
+                .iter()
+                .chain(&LINT_ATTRIBUTES)
+                .any(|name| {
+                    attr.path()
+                        .is_ident(name)
+                })
+        })
+        .cloned()
+        .collect();
+
+    function
+        .attrs
+        .retain(|attr| {
+            !SERVICE_ATTRIBUTES
+                .iter()
+                .any(|name| {
+                    attr.path()
+                        .is_ident(name)
+                })
+        });
+
+    attributes
+}
+
+/// **`parse_parameter()`**
+///
+/// Parses a handler parameter with its `#[error_override(..)]`
+/// and source attributes, only one source may be declared.
+fn parse_parameter(parameter: &PatType) -> SynResult<ProofRouteParameter> {
+    let error_override = parameter
+        .attrs
+        .iter()
+        .find(|attribute| {
+            attribute
+                .path()
+                .is_ident("error_override")
+        })
+        .map(Attribute::parse_args::<ErrorOverride>)
+        .transpose()?;
+
+    let validation = parameter
+        .attrs
+        .iter()
+        .find(|attribute| {
+            attribute
+                .path()
+                .is_ident("validate")
+        })
+        .map(parse_validation)
+        .transpose()?;
+
+    let mut source = None;
+
+    for attribute in &parameter.attrs {
+        let Some(kind) = PARAMETER_ATTRIBUTES[2..]
+            .iter()
+            .find(|kind| {
+                attribute
+                    .path()
+                    .is_ident(kind)
+            })
+        else {
+            continue;
+        };
+
+        if source.is_some() {
+            return Err(SynError::new_spanned(
+                attribute,
+                "Only one of path, query, header or cookie may be declared per parameter.",
+            ));
+        }
+
+        let name = match &attribute.meta {
+            Meta::Path(_) => {
+                let Pat::Ident(PatIdent { ident, .. }) = &*parameter.pat else {
+                    return Err(SynError::new_spanned(
+                        attribute,
+                        format!("Declare the {kind} name as #[{kind}(\"name\")] for this pattern."),
+                    ));
+                };
+
+                let name = ident
+                    .unraw()
+                    .to_string();
+
+                if *kind == "header" { name.replace('_', "-") } else { name }
+            },
+            _ => attribute
+                .parse_args::<LitStr>()?
+                .value(),
+        };
+
+        source = Some(match *kind {
+            "path" => ParameterSource::Path(name),
+            "query" => ParameterSource::Query(name),
+            "header" => ParameterSource::Header(name),
+            _ => ParameterSource::Cookie(name),
+        });
+    }
+
+    Ok(ProofRouteParameter {
+        error_override,
+        validation,
+        source,
+        ty: (*parameter.ty).clone(),
+    })
+}
+
+/// **`parse_validation()`**
+///
+/// Parses the `#[validate(..)]` attribute options, failing
+/// if the backend isn't declared.
+fn parse_validation(attr: &Attribute) -> SynResult<Validation> {
+    let mut backend = None;
+    let mut on_error = None;
+
+    if let Meta::List(_) = &attr.meta {
+        attr.parse_args_with(|input: ParseStream| {
+            while !input.is_empty() {
+                let key = input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+
+                match key
+                    .to_string()
+                    .as_str()
+                {
+                    "backend" if backend.is_some() => {
+                        return Err(SynError::new_spanned(key, "backend was already declared."));
+                    },
+                    "backend" => backend = Some(input.parse::<ValidationBackend>()?),
+                    "on_error" if on_error.is_some() => {
+                        return Err(SynError::new_spanned(key, "on_error was already declared."));
+                    },
+                    "on_error" => on_error = Some(input.parse::<Expr>()?),
+                    _ => {
+                        return Err(SynError::new_spanned(
+                            &key,
+                            format!(
+                                "{key} is not a valid validate option, expected any of backend, \
+                                 on_error."
+                            ),
+                        ));
+                    },
+                }
+
+                if input.is_empty() {
+                    break;
+                }
+
+                input.parse::<Token![,]>()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    // not inferred from the enabled features, as features are unified.
+    let Some(backend) = backend else {
+        return Err(SynError::new_spanned(
+            attr,
+            "#[validate(..)] requires a backend, declare backend = validator or backend = garde.",
+        ));
+    };
+
+    Ok(Validation { backend, on_error })
+}
+
+/// **`strip_parameter_attributes()`**
+///
+/// Removes the attributes parsed from the handler
+/// parameters, which are not valid in functions.
+pub fn strip_parameter_attributes(sig: &mut Signature) {
+    for input in &mut sig.inputs {
+        if let FnArg::Typed(typed) = input {
+            typed
+                .attrs
+                .retain(|attr| {
+                    !PARAMETER_ATTRIBUTES
+                        .iter()
+                        .any(|name| {
+                            attr.path()
+                                .is_ident(name)
+                        })
+                });
+        }
+    }
+}
+
+impl Parse for ErrorOverride {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if parse_kind_pattern(&input.fork()).is_ok() {
+            return parse_kind_arms(input).map(Self::Kinds);
+        }
+
+        let fork = input.fork();
+
+        if fork
+            .parse::<Ident>()
+            .is_ok_and(|key| key == "with")
+            && fork.peek(Token![=])
+            && !fork.peek(Token![==])
+        {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            return input
+                .parse::<Expr>()
+                .map(Self::With)
+                .map_err(|err| SynError::new(err.span(), "Expected a function."));
+        }
+
+        input
+            .parse::<Expr>()
+            .map(Self::Variant)
+            .map_err(|err| SynError::new(err.span(), "Expected an expression."))
+    }
+}
+
+impl ErrorOverride {
+    /// **`ErrorOverride.has_wildcard`**
+    ///
+    /// Whether the override handles every error, which is
+    /// the case unless it has `Kind => Variant` arms without
+    /// a `_` wildcard.
+    #[inline]
+    pub fn has_wildcard(&self) -> bool {
+        match self {
+            Self::Kinds(arms) => arms
+                .iter()
+                .any(|arm| {
+                    arm.kinds
+                        .is_empty()
+                }),
+            _ => true,
+        }
+    }
+}
+
+impl ErrorKindArm {
+    /// **`ErrorKindArm.kinds`**
+    ///
+    /// The error kinds this arm matches,
+    /// empty for the `_` wildcard.
+    #[inline]
+    pub fn kinds(&self) -> &[Ident] {
+        &self.kinds
+    }
+
+    /// **`ErrorKindArm.variant`**
+    ///
+    /// The variant expression the matched errors
+    /// are rendered as.
+    #[inline]
+    pub fn variant(&self) -> &Expr {
+        &self.variant
+    }
+}
+
+/// **`parse_kind_pattern()`**
+///
+/// Parses a `Kind | Kind =>` or `_ =>` pattern,
+/// returning an empty list for the wildcard.
+fn parse_kind_pattern(input: ParseStream) -> SynResult<Vec<Ident>> {
+    let mut kinds = Vec::new();
+
+    if input.peek(Token![_]) {
+        input.parse::<Token![_]>()?;
+    } else {
+        loop {
+            kinds.push(input.parse::<Ident>()?);
+
+            if !input.peek(Token![|]) {
+                break;
+            }
+
+            input.parse::<Token![|]>()?;
+        }
+    }
+
+    input.parse::<Token![=>]>()?;
+
+    Ok(kinds)
+}
+
+/// **`closest_kind()`**
+///
+/// Applies the edit distance algorithm to a typoed
+/// error kind identifier and returns the most-likely
+/// to be error kind.
+fn closest_kind(target_kind: &str) -> &str {
+    ERROR_KINDS
+        .iter()
+        .min_by_key(|kind| levenshtein(kind, target_kind))
+        .map_or_else(|| unreachable!(), |kind| *kind)
+}
+
+/// **`parse_kind_arms()`**
+///
+/// Parses the `Kind => Variant` arms of a kind specific
+/// `#[error_override(..)]`, failing for unknown or repeated
+/// kinds and arms after the `_` wildcard.
+fn parse_kind_arms(input: ParseStream) -> SynResult<Vec<ErrorKindArm>> {
+    let mut arms = Vec::<ErrorKindArm>::new();
+
+    while !input.is_empty() {
+        if arms
+            .last()
+            .is_some_and(|arm| {
+                arm.kinds
+                    .is_empty()
+            })
+        {
+            return Err(input.error("The _ arm must be the last one."));
+        }
+
+        let kinds = parse_kind_pattern(input)?;
+
+        for kind in &kinds {
+            let kind_string = kind.to_string();
+
+            if !ERROR_KINDS.contains(&kind_string.as_str()) {
+                return Err(SynError::new_spanned(
+                    kind,
+                    format!(
+                        "{kind_string} is not a valid extractor error kind, did you mean {}? \
+                         expected any of {}.",
+                        closest_kind(&kind_string),
+                        ERROR_KINDS.join(", ")
+                    ),
+                ));
+            }
+
+            if arms
+                .iter()
+                .flat_map(ErrorKindArm::kinds)
+                .chain(
+                    kinds
+                        .iter()
+                        .take_while(|previous| *previous != kind),
+                )
+                .any(|previous| previous == kind)
+            {
+                return Err(SynError::new_spanned(
+                    kind,
+                    format!("The {kind_string} kind was already declared."),
+                ));
+            }
+        }
+
+        let variant = input
+            .parse::<Expr>()
+            .map_err(|err| SynError::new(err.span(), "Expected an expression."))?;
+
+        arms.push(ErrorKindArm { kinds, variant });
+
+        if input.is_empty() {
+            break;
+        }
+
+        input.parse::<Token![,]>()?;
+    }
+
+    Ok(arms)
+}
+
+impl Validation {
+    /// **`Validation.backend`**
+    ///
+    /// The crate the parameter is validated with.
+    #[inline]
+    pub const fn backend(&self) -> ValidationBackend {
+        self.backend
+    }
+
+    /// **`Validation.on_error`**
+    ///
+    /// The variant expression declared with `on_error = ..`,
+    /// which may use the `err`, `fields` and `req` bindings.
+    #[inline]
+    pub const fn on_error(&self) -> Option<&Expr> {
+        self.on_error
+            .as_ref()
+    }
+}
+
+impl Parse for ValidationBackend {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        let ident_string = ident.to_string();
+
+        let backend = match ident_string.as_str() {
+            "validator" => Self::Validator,
+            "garde" => Self::Garde,
+            _ => {
+                return Err(SynError::new_spanned(
+                    ident,
+                    format!(
+                        "{ident_string} is not a valid validation backend, expected validator or \
+                         garde."
+                    ),
+                ));
+            },
+        };
+
+        if !cfg!(feature = "validator") && backend == Self::Validator
+            || !cfg!(feature = "garde") && backend == Self::Garde
+        {
+            return Err(SynError::new_spanned(
+                ident,
+                format!(
+                    "The {ident_string} validation backend requires the \"{ident_string}\" \
+                     feature of actix_failwrap to be enabled."
+                ),
+            ));
+        }
+
+        Ok(backend)
+    }
+}
+
+impl ParameterSource {
+    /// **`ParameterSource.name`**
+    ///
+    /// The name the parameter is looked up by.
+    #[inline]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Path(name) | Self::Query(name) | Self::Header(name) | Self::Cookie(name) => name,
+        }
+    }
+
+    /// **`ParameterSource.kind`**
+    ///
+    /// The source name as written in the attribute.
+    #[inline]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Path(_) => "path",
+            Self::Query(_) => "query",
+            Self::Header(_) => "header",
+            Self::Cookie(_) => "cookie",
+        }
+    }
+}
+
 impl ProofRouteParameter {
     /// **`ProofRouteParameter.error_override`**
     ///
     /// The error override for this collector if annotated
     /// with `#[error_override(..)]`, otherwise None.
     #[inline]
-    pub const fn error_override(&self) -> Option<&Expr> {
+    pub const fn error_override(&self) -> Option<&ErrorOverride> {
         self.error_override
             .as_ref()
     }
 
+    /// **`ProofRouteParameter.source`**
+    ///
+    /// Where the parameter is extracted from if annotated
+    /// with a source attribute, otherwise the parameter
+    /// type is used as an extractor.
+    #[inline]
+    pub const fn source(&self) -> Option<&ParameterSource> {
+        self.source
+            .as_ref()
+    }
+
+    /// **`ProofRouteParameter.validation`**
+    ///
+    /// The validation run after the extraction if
+    /// annotated with `#[validate(..)]`, otherwise None.
+    #[inline]
+    pub const fn validation(&self) -> Option<&Validation> {
+        self.validation
+            .as_ref()
+    }
+
     /// **`ProofRouteParameter.ty`**
     ///
     /// The collector type.
@@ -0,0 +1,589 @@
+//! [`proof_scope`] Input Module
+//!
+//! Declares meta structs that implement
+//! parsing methods for the [`proof_scope`]
+//! macro.
+//!
+//! [`proof_scope`]: crate::proof_scope
+
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    Attribute,
+    Error as SynError,
+    Expr,
+    GenericParam,
+    Ident,
+    ImplItem,
+    Item,
+    ItemMod,
+    LitBool,
+    LitStr,
+    Result as SynResult,
+    Token,
+    Type,
+};
+
+use crate::helpers::macro_attrs::{append_macro_option, is_macro_attr};
+use crate::macro_input::proof_controller::ProofControllerMeta;
+use crate::macro_input::proof_route::{ProofRouteMeta, parse_auto_register};
+
+/// **`ProofScopeMeta`**
+///
+/// The path prefix and the defaults for
+/// the routes inside the scope.
+#[derive(Debug)]
+pub struct ProofScopeMeta {
+    prefix: String,
+    scope_prefix: Option<LitStr>,  // the prefix of the enclosing scopes.
+    error: Option<Type>,           // default for the routes without `error = ..`.
+    extractor_error: Option<Expr>, // default for the routes inheriting `error`.
+    auto_register: Option<bool>,   // submitted to the route registry if true.
+}
+
+/// **`ProofScopeBody`**
+///
+/// The scope module metadata.
+#[derive(Debug)]
+pub struct ProofScopeBody {
+    registrations: Vec<ScopeRegistration>,
+    routes: Vec<ScopeRoute>,
+    item_mod: ItemMod,
+}
+
+/// **`ScopeRoute`**
+///
+/// A route registered by the scope, either directly or
+/// through its nested scopes and controllers, with its
+/// full path and the `cfg` attributes gating it.
+#[derive(Debug)]
+pub struct ScopeRoute {
+    method: String,
+    path: String,
+    cfg_attributes: Vec<Attribute>,
+}
+
+/// **`ScopeRegistration`**
+///
+/// An item registered in the scope `configure` function
+/// in declaration order, alongside its `cfg` attributes.
+#[derive(Debug)]
+pub enum ScopeRegistration {
+    Route(Ident, Vec<Attribute>),
+    Scope(Ident, Vec<Attribute>),
+    Controller(Type, Vec<Attribute>),
+}
+
+impl ProofScopeMeta {
+    /// **`ProofScopeMeta.prefix`**
+    ///
+    /// The path prefix for the scope routes.
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// **`ProofScopeMeta.full_prefix`**
+    ///
+    /// The path prefix for the scope routes including
+    /// the `scope_prefix = ".."` of the enclosing scopes,
+    /// which is passed down to the nested items.
+    pub fn full_prefix(&self) -> String {
+        self.scope_prefix
+            .as_ref()
+            .map_or_else(String::new, LitStr::value)
+            + &self.prefix
+    }
+
+    /// **`ProofScopeMeta.error`**
+    ///
+    /// The error type declared with `error = ..`, used
+    /// by the routes and nested scopes not declaring one.
+    #[inline]
+    pub const fn error(&self) -> Option<&Type> {
+        self.error
+            .as_ref()
+    }
+
+    /// **`ProofScopeMeta.extractor_error`**
+    ///
+    /// The variant declared with `extractor_error = ..`, used
+    /// by the routes and nested scopes inheriting the scope
+    /// error type that don't declare their own.
+    #[inline]
+    pub const fn extractor_error(&self) -> Option<&Expr> {
+        self.extractor_error
+            .as_ref()
+    }
+
+    /// **`ProofScopeMeta.auto_register`**
+    ///
+    /// The `auto_register = ..` option if declared, scopes are
+    /// only submitted to the route registry when it's true.
+    #[inline]
+    pub const fn auto_register(&self) -> Option<bool> {
+        self.auto_register
+    }
+}
+
+impl Parse for ProofScopeMeta {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let prefix = input
+            .parse::<LitStr>()?
+            .value();
+        let mut scope_prefix = None;
+        let mut error = None;
+        let mut extractor_error = None;
+        let mut auto_register = None;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            match key
+                .to_string()
+                .as_str()
+            {
+                "scope_prefix" if scope_prefix.is_some() => {
+                    return Err(SynError::new_spanned(key, "scope_prefix was already declared."));
+                },
+                "scope_prefix" => scope_prefix = Some(input.parse::<LitStr>()?),
+                "error" if error.is_some() => {
+                    return Err(SynError::new_spanned(key, "error was already declared."));
+                },
+                "error" => error = Some(input.parse::<Type>()?),
+                "extractor_error" if extractor_error.is_some() => {
+                    return Err(SynError::new_spanned(
+                        key,
+                        "extractor_error was already declared.",
+                    ));
+                },
+                "extractor_error" => extractor_error = Some((key, input.parse::<Expr>()?)),
+                "auto_register" if auto_register.is_some() => {
+                    return Err(SynError::new_spanned(key, "auto_register was already declared."));
+                },
+                "auto_register" => {
+                    auto_register = Some(parse_auto_register(&input.parse::<LitBool>()?)?);
+                },
+                _ => {
+                    return Err(SynError::new_spanned(
+                        &key,
+                        format!(
+                            "{key} is not a valid proof_scope option, expected any of \
+                             scope_prefix, error, extractor_error, auto_register."
+                        ),
+                    ));
+                },
+            }
+        }
+
+        if let Some((key, _)) = &extractor_error
+            && error.is_none()
+        {
+            return Err(SynError::new_spanned(
+                key,
+                "extractor_error requires the scope error = .. option.",
+            ));
+        }
+
+        Ok(Self {
+            prefix,
+            scope_prefix,
+            error,
+            extractor_error: extractor_error.map(|(_, variant)| variant),
+            auto_register,
+        })
+    }
+}
+
+impl ProofScopeBody {
+    /// **`ProofScopeBody::parse_with_meta()`**
+    ///
+    /// Parses the scope module, collecting its routes, nested
+    /// scopes and controllers while passing the scope defaults
+    /// to the nested macro attributes.
+    pub fn parse_with_meta(input: ParseStream, meta: &ProofScopeMeta) -> SynResult<Self> {
+        let mut item_mod = input.parse::<ItemMod>()?;
+
+        let Some((_, items)) = &mut item_mod.content else {
+            return Err(SynError::new_spanned(
+                &item_mod,
+                "proof_scope can only be applied to inline modules.",
+            ));
+        };
+
+        let mut registrations = Vec::new();
+
+        for item in items {
+            match item {
+                Item::Fn(function) => {
+                    let Some(attr) = function
+                        .attrs
+                        .iter_mut()
+                        .find(|attr| is_macro_attr(attr, "proof_route"))
+                    else {
+                        continue;
+                    };
+
+                    if function
+                        .sig
+                        .generics
+                        .params
+                        .iter()
+                        .any(|param| !matches!(param, GenericParam::Lifetime(_)))
+                    {
+                        return Err(SynError::new_spanned(
+                            &function
+                                .sig
+                                .generics,
+                            "Generic handlers can't be registered by proof_scope, register their \
+                             factories manually.",
+                        ));
+                    }
+
+                    let route_meta = inject_route_defaults(attr, meta)?;
+                    disable_auto_register(attr, route_meta.auto_register())?;
+
+                    registrations.push(ScopeRegistration::Route(
+                        function
+                            .sig
+                            .ident
+                            .clone(),
+                        cfg_attributes(&function.attrs),
+                    ));
+                },
+                Item::Mod(module) => {
+                    let Some(attr) = module
+                        .attrs
+                        .iter_mut()
+                        .find(|attr| is_macro_attr(attr, "proof_scope"))
+                    else {
+                        continue;
+                    };
+
+                    inject_scope_defaults(attr, meta)?;
+
+                    registrations.push(ScopeRegistration::Scope(
+                        module
+                            .ident
+                            .clone(),
+                        cfg_attributes(&module.attrs),
+                    ));
+                },
+                Item::Impl(item_impl) => {
+                    let Some(attr) = item_impl
+                        .attrs
+                        .iter_mut()
+                        .find(|attr| is_macro_attr(attr, "proof_controller"))
+                    else {
+                        continue;
+                    };
+
+                    let controller_meta = attr.parse_args::<ProofControllerMeta>()?;
+                    disable_auto_register(attr, controller_meta.auto_register())?;
+
+                    for impl_item in &mut item_impl.items {
+                        let ImplItem::Fn(method) = impl_item else {
+                            continue;
+                        };
+
+                        if let Some(attr) = method
+                            .attrs
+                            .iter_mut()
+                            .find(|attr| is_macro_attr(attr, "proof_route"))
+                        {
+                            inject_route_defaults(attr, meta)?;
+                        }
+                    }
+
+                    registrations.push(ScopeRegistration::Controller(
+                        (*item_impl.self_ty).clone(),
+                        cfg_attributes(&item_impl.attrs),
+                    ));
+                },
+                _ => {},
+            }
+        }
+
+        let routes = item_mod
+            .content
+            .as_ref()
+            .map(|(_, items)| collect_routes(items, meta.prefix(), &[]))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self { registrations, routes, item_mod })
+    }
+
+    /// **`ProofScopeBody.registrations`**
+    ///
+    /// The routes, nested scopes and controllers
+    /// registered by the scope in declaration order.
+    #[inline]
+    pub fn registrations(&self) -> &[ScopeRegistration] {
+        &self.registrations
+    }
+
+    /// **`ProofScopeBody.routes`**
+    ///
+    /// Every route registered by the scope, including
+    /// the routes of its nested scopes and controllers.
+    #[inline]
+    pub fn routes(&self) -> &[ScopeRoute] {
+        &self.routes
+    }
+
+    /// **`ProofScopeBody.item_mod`**
+    ///
+    /// The scope module with the defaults passed
+    /// to the nested macro attributes.
+    #[inline]
+    pub fn item_mod(&self) -> &ItemMod {
+        &self.item_mod
+    }
+}
+
+impl ScopeRoute {
+    /// **`ScopeRoute.method`**
+    ///
+    /// The uppercase HTTP method of the route.
+    #[inline]
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// **`ScopeRoute.path`**
+    ///
+    /// The route path including the scope prefixes.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// **`ScopeRoute.cfg_attributes`**
+    ///
+    /// The `cfg` attributes of the route and
+    /// every item it is nested in.
+    #[inline]
+    pub fn cfg_attributes(&self) -> &[Attribute] {
+        &self.cfg_attributes
+    }
+}
+
+/// **`inject_route_defaults()`**
+///
+/// Appends the scope `error = ..` and `extractor_error = ..`
+/// defaults and the scope prefix to a `#[proof_route(..)]`
+/// attribute not declaring them, returns the route meta as
+/// declared.
+fn inject_route_defaults(attr: &mut Attribute, meta: &ProofScopeMeta) -> SynResult<ProofRouteMeta> {
+    let route_meta = attr.parse_args::<ProofRouteMeta>()?;
+
+    if let Some(error) = meta.error()
+        && route_meta
+            .error()
+            .is_none()
+    {
+        append_macro_option(attr, &quote! { error = #error });
+
+        if let Some(extractor_error) = meta.extractor_error()
+            && route_meta
+                .extractor_error()
+                .is_none()
+        {
+            append_macro_option(attr, &quote! { extractor_error = #extractor_error });
+        }
+    }
+
+    if route_meta
+        .scope_segments()
+        .is_none()
+    {
+        let scope_prefix = meta.full_prefix();
+
+        append_macro_option(attr, &quote! { scope_prefix = #scope_prefix });
+    }
+
+    Ok(route_meta)
+}
+
+/// **`inject_scope_defaults()`**
+///
+/// Appends the scope `error = ..` and `extractor_error = ..`
+/// defaults and the scope prefix to a nested `#[proof_scope(..)]`
+/// attribute not declaring them, and disables its registry
+/// submission.
+fn inject_scope_defaults(attr: &mut Attribute, meta: &ProofScopeMeta) -> SynResult<()> {
+    let scope_meta = attr.parse_args::<ProofScopeMeta>()?;
+
+    if let Some(error) = meta.error()
+        && scope_meta
+            .error()
+            .is_none()
+    {
+        append_macro_option(attr, &quote! { error = #error });
+
+        if let Some(extractor_error) = meta.extractor_error()
+            && scope_meta
+                .extractor_error()
+                .is_none()
+        {
+            append_macro_option(attr, &quote! { extractor_error = #extractor_error });
+        }
+    }
+
+    if scope_meta
+        .scope_prefix
+        .is_none()
+    {
+        let scope_prefix = meta.full_prefix();
+
+        append_macro_option(attr, &quote! { scope_prefix = #scope_prefix });
+    }
+
+    disable_auto_register(attr, scope_meta.auto_register())
+}
+
+/// **`disable_auto_register()`**
+///
+/// Appends `auto_register = false` to a nested macro
+/// attribute, as the item is already registered by
+/// the scope `configure` function.
+fn disable_auto_register(attr: &mut Attribute, declared: Option<bool>) -> SynResult<()> {
+    if declared.is_some() {
+        return Err(SynError::new_spanned(
+            attr,
+            "auto_register can't be declared inside a proof_scope, the scope registers its items.",
+        ));
+    }
+
+    append_macro_option(attr, &quote! { auto_register = false });
+
+    Ok(())
+}
+
+/// **`collect_routes()`**
+///
+/// Collects the method and full path of every route declared
+/// in the scope items, recursing into nested scopes and
+/// controllers with their prefixes appended.
+fn collect_routes(
+    items: &[Item],
+    prefix: &str,
+    cfg_attrs: &[Attribute],
+) -> SynResult<Vec<ScopeRoute>> {
+    let mut routes = Vec::new();
+
+    let route = |attr: &Attribute, prefix: &str, cfg_attributes: Vec<Attribute>| {
+        attr.parse_args::<ProofRouteMeta>()
+            .map(|meta| {
+                meta.method_paths()
+                    .map(|(method, path)| ScopeRoute {
+                        method: method.to_string(),
+                        path: format!("{prefix}{path}"),
+                        cfg_attributes: cfg_attributes.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+    };
+
+    for item in items {
+        match item {
+            Item::Fn(function) => {
+                if let Some(attr) = function
+                    .attrs
+                    .iter()
+                    .find(|attr| is_macro_attr(attr, "proof_route"))
+                {
+                    routes.extend(route(attr, prefix, nested_cfg(cfg_attrs, &function.attrs))?);
+                }
+            },
+            Item::Mod(module) => {
+                if let Some(attr) = module
+                    .attrs
+                    .iter()
+                    .find(|attr| is_macro_attr(attr, "proof_scope"))
+                    && let Some((_, items)) = &module.content
+                {
+                    let scope_prefix = attr
+                        .parse_args::<ProofScopeMeta>()?
+                        .prefix
+                        .clone();
+
+                    routes.extend(collect_routes(
+                        items,
+                        &format!("{prefix}{scope_prefix}"),
+                        &nested_cfg(cfg_attrs, &module.attrs),
+                    )?);
+                }
+            },
+            Item::Impl(item_impl) => {
+                let Some(attr) = item_impl
+                    .attrs
+                    .iter()
+                    .find(|attr| is_macro_attr(attr, "proof_controller"))
+                else {
+                    continue;
+                };
+
+                let controller_prefix = format!(
+                    "{prefix}{}",
+                    attr.parse_args::<ProofControllerMeta>()?
+                        .prefix()
+                );
+                let impl_cfg = nested_cfg(cfg_attrs, &item_impl.attrs);
+
+                for impl_item in &item_impl.items {
+                    if let ImplItem::Fn(method) = impl_item
+                        && let Some(attr) = method
+                            .attrs
+                            .iter()
+                            .find(|attr| is_macro_attr(attr, "proof_route"))
+                    {
+                        routes.extend(route(
+                            attr,
+                            &controller_prefix,
+                            nested_cfg(&impl_cfg, &method.attrs),
+                        )?);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Ok(routes)
+}
+
+/// **`nested_cfg()`**
+///
+/// Appends the `cfg` attributes of a nested item
+/// to the ones of the items it is nested in.
+fn nested_cfg(cfg_attrs: &[Attribute], attrs: &[Attribute]) -> Vec<Attribute> {
+    cfg_attrs
+        .iter()
+        .cloned()
+        .chain(cfg_attributes(attrs))
+        .collect()
+}
+
+/// **`cfg_attributes()`**
+///
+/// Collects the `cfg` attributes of an item, which
+/// also gate its registration.
+fn cfg_attributes(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            attr.path()
+                .is_ident("cfg")
+        })
+        .cloned()
+        .collect()
+}
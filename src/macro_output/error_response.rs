@@ -6,16 +6,26 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{TokenStreamExt, format_ident, quote};
-use syn::Fields as VariantFields;
+use syn::{Fields as VariantFields, Ident, LitStr};
 
-use crate::macro_input::error_response::{ErrorResponse, ErrorResponseVariant};
+use crate::helpers::formatting::{FIELD_BINDING_PREFIX, bind_format_arguments};
+use crate::helpers::status_codes::status_to_code;
+use crate::macro_input::error_response::{ErrorResponse, ErrorResponseVariant, ResolvedVariant};
+use crate::macro_output::response_format::{
+    format_envelope,
+    format_helpers,
+    format_html_template,
+    format_jsonapi_source,
+    format_negotiation,
+    formatted_response,
+};
 
 /// **`variant_match_head()`**
 ///
 /// Generates an 'ignore all' head depending on the
 /// provided enum variant. Returns a [`TokenStream2`]
 /// with the generated head.
-fn variant_match_head(variant: &ErrorResponseVariant) -> TokenStream2 {
+pub fn variant_match_head(variant: &ErrorResponseVariant) -> TokenStream2 {
     let variant_name = &variant
         .variant()
         .ident;
@@ -31,6 +41,397 @@ fn variant_match_head(variant: &ErrorResponseVariant) -> TokenStream2 {
     quote! { Self::#variant_name #variant_head_type => }
 }
 
+/// **`variant_binding_head()`**
+///
+/// Generates a head binding every field of the provided
+/// enum variant under its [`field_binding`], so fields can't
+/// shadow the generated locals. Returns a [`TokenStream2`]
+/// with the generated head.
+pub fn variant_binding_head(variant: &ErrorResponseVariant) -> TokenStream2 {
+    let variant_name = &variant
+        .variant()
+        .ident;
+    let variant_head_type = match &variant
+        .variant()
+        .fields
+    {
+        VariantFields::Named(fields) => {
+            let (names, bindings) = fields
+                .named
+                .iter()
+                .filter_map(|field| {
+                    field
+                        .ident
+                        .as_ref()
+                })
+                .map(|name| (name, field_binding(&name.to_string())))
+                .collect::<(Vec<_>, Vec<_>)>();
+
+            quote! { { #(#names: #bindings),* } }
+        },
+        VariantFields::Unnamed(fields) => {
+            let names = (0..fields
+                .unnamed
+                .len())
+                .map(|idx| field_binding(&idx.to_string()));
+
+            quote! { ( #(#names),* ) }
+        },
+        VariantFields::Unit => quote! {},
+    };
+
+    quote! {
+        #[allow(unused_variables)]
+        Self::#variant_name #variant_head_type =>
+    }
+}
+
+/// **`field_binding()`**
+///
+/// The identifier a variant field is bound as by
+/// [`variant_binding_head`], the field name or index
+/// prefixed such as `__field_id` or `__field_0`.
+pub fn field_binding(name: &str) -> Ident {
+    format_ident!("{FIELD_BINDING_PREFIX}{name}")
+}
+
+/// **`variant_message()`**
+///
+/// Generates the expression that obtains the message
+/// for the provided variant, which is the `Display`
+/// output unless a translation was negotiated.
+fn variant_message(variant: &ErrorResponseVariant) -> TokenStream2 {
+    let display = quote! { ::std::string::ToString::to_string(&self) };
+
+    if variant
+        .messages()
+        .is_empty()
+    {
+        return display;
+    }
+
+    let fields = variant
+        .variant()
+        .fields
+        .iter()
+        .filter_map(|field| {
+            field
+                .ident
+                .as_ref()
+        })
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let translations = variant
+        .messages()
+        .iter()
+        .map(|message| {
+            let lang = message.lang();
+            let format = LitStr::new(
+                &bind_format_arguments(
+                    &message
+                        .format()
+                        .value(),
+                    &fields,
+                ),
+                message
+                    .format()
+                    .span(),
+            );
+
+            quote! { ::std::option::Option::Some(#lang) => ::std::format!(#format) }
+        });
+
+    quote! {
+        match request.and_then(Self::__failwrap_language) {
+            #(#translations,)*
+            _ => #display
+        }
+    }
+}
+
+/// **`http_response_variant()`**
+///
+/// Generates the match arm that obtains the response
+/// builder, numeric status, code and message for
+/// the provided variant.
+fn http_response_variant(variant: &ErrorResponseVariant, status_code: &Ident) -> TokenStream2 {
+    let mut http_response_variant = if variant
+        .messages()
+        .is_empty()
+    {
+        variant_match_head(variant)
+    } else {
+        variant_binding_head(variant)
+    };
+
+    let status =
+        u16::try_from(status_to_code(&status_code.to_string()).unwrap_or_else(|| unreachable!()))
+            .unwrap_or_else(|_| unreachable!());
+    let code = variant
+        .variant()
+        .ident
+        .to_string();
+    let message = variant_message(variant);
+
+    http_response_variant.append_all(quote! {
+        (::actix_web::HttpResponse::#status_code(), #status, #code, #message)
+    });
+
+    http_response_variant
+}
+
+/// **`language_negotiation()`**
+///
+/// Generates a function that negotiates the message
+/// language from the request `Accept-Language` header,
+/// nothing is generated if there are no translations.
+fn language_negotiation(input: &ErrorResponse) -> TokenStream2 {
+    let languages = input.languages();
+
+    if languages.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        #[doc(hidden)]
+        fn __failwrap_language(
+            request: &::actix_web::HttpRequest
+        ) -> ::std::option::Option<&'static str> {
+            const LANGUAGES: &[&str] = &[#(#languages),*];
+
+            let mut accept_language =
+                <::actix_web::http::header::AcceptLanguage as ::actix_web::http::header::Header>
+                    ::parse(request)
+                    .ok()?;
+
+            // languages with a zero quality are explicitly not acceptable.
+            accept_language
+                .0
+                .retain(|item| item.quality != ::actix_web::http::header::Quality::ZERO);
+            accept_language
+                .ranked()
+                .into_iter()
+                .find_map(|preference| match preference {
+                    ::actix_web::http::header::Preference::Specific(tag) => LANGUAGES
+                        .iter()
+                        .copied()
+                        .find(|language| language.eq_ignore_ascii_case(tag.as_str()))
+                        .or_else(|| {
+                            LANGUAGES
+                                .iter()
+                                .copied()
+                                .find(|language| {
+                                    language.eq_ignore_ascii_case(tag.primary_language())
+                                })
+                        }),
+                    ::actix_web::http::header::Preference::Any => ::std::option::Option::None,
+                })
+        }
+    }
+}
+
+/// **`extractor_fallback()`**
+///
+/// Generates the function the route handlers call for
+/// extractor errors without an override nor an entry in
+/// the extractor error table, which returns the
+/// `#[extractor_fallback(..)]` variant if declared and
+/// the original error otherwise.
+fn extractor_fallback(input: &ErrorResponse) -> TokenStream2 {
+    let body = input
+        .extractor_fallback()
+        .map_or_else(
+            || quote! { ::std::result::Result::Err(err) },
+            |variant| quote! { ::std::result::Result::Ok(Self::#variant) },
+        );
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_variables)]
+        pub fn __failwrap_extractor_fallback(
+            err: ::actix_web::Error,
+            req: &::actix_web::HttpRequest
+        ) -> ::std::result::Result<Self, ::actix_web::Error> {
+            #body
+        }
+    }
+}
+
+/// **`extractor_key_type()`**
+///
+/// The extractor type an `#[extractor_errors(..)]` key
+/// is implemented for with its generics, the web
+/// extractors are generic over their inner type.
+fn extractor_key_type(extractor: &Ident) -> (TokenStream2, TokenStream2) {
+    if extractor == "Cookie" {
+        return (quote! {}, quote! { ::actix_web::cookie::Cookie<'static> });
+    }
+
+    (quote! { <__T> }, quote! { ::actix_web::web::#extractor<__T> })
+}
+
+/// **`extractor_error_table()`**
+///
+/// Generates the hidden extractor error table of the enum,
+/// which implements `Index` for each extractor type mapped
+/// by `#[extractor_errors(..)]`, so the route handlers
+/// resolve the variant of a parameter by its type at
+/// compile time, aliases included.
+fn extractor_error_table(input: &ErrorResponse) -> TokenStream2 {
+    let enum_name = input.enum_name();
+    let visibility = input.visibility();
+
+    let entries = input
+        .extractor_errors()
+        .iter()
+        .map(|extractor_error| {
+            let (generics, key_type) = extractor_key_type(extractor_error.extractor());
+            let variant = extractor_error.variant();
+
+            quote! {
+                impl #generics ::std::ops::Index<::std::marker::PhantomData<#key_type>>
+                for __FailwrapExtractorErrors {
+                    type Output = fn(::actix_web::Error, &::actix_web::HttpRequest) -> #enum_name;
+
+                    fn index(&self, _: ::std::marker::PhantomData<#key_type>) -> &Self::Output {
+                        #[allow(unused_variables)]
+                        const VARIANT: fn(::actix_web::Error, &::actix_web::HttpRequest) -> #enum_name
+                            = |err, req| #enum_name::#variant;
+
+                        &VARIANT
+                    }
+                }
+            }
+        });
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #visibility struct __FailwrapExtractorErrors;
+
+            impl #enum_name {
+                #[doc(hidden)]
+                #visibility const __FAILWRAP_EXTRACTOR_ERRORS: __FailwrapExtractorErrors
+                    = __FailwrapExtractorErrors;
+            }
+
+            #(#entries)*
+        };
+    }
+}
+
+/// **`variant_resolver()`**
+///
+/// Generates a hidden macro named after the enum, which
+/// the route handlers invoke through the error type path
+/// to construct the override variants. It forwards them
+/// with the variant names to `__failwrap_variant!`, so
+/// misspelled variants are reported in the attribute
+/// along with the closest variant at macro time.
+fn variant_resolver(input: &ErrorResponse) -> TokenStream2 {
+    let enum_name = input.enum_name();
+    let module_name = format_ident!("__failwrap_{enum_name}");
+    let variants = input
+        .variants()
+        .iter()
+        .map(|variant| {
+            &variant
+                .variant()
+                .ident
+        });
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod #module_name {
+            #[allow(unused_macros)]
+            macro_rules! #enum_name {
+                ($($tokens:tt)*) => {
+                    ::actix_failwrap::__failwrap_variant! { [#(#variants),*] $($tokens)* }
+                };
+            }
+
+            pub(crate) use #enum_name;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #module_name::#enum_name;
+    }
+}
+
+/// **`resolved_variant_output()`**
+///
+/// Generates the construction of a variant resolved
+/// by `__failwrap_variant!` from the error type.
+pub fn resolved_variant_output(input: &ResolvedVariant) -> TokenStream2 {
+    let error = input.error();
+    let variant = input.variant();
+
+    quote! { #error::#variant }
+}
+
+/// **`http_response_body()`**
+///
+/// Generates the `__failwrap_response` body, which renders
+/// the response as plain text, through the response transformer,
+/// from the HTML templates or in the negotiated format.
+fn http_response_body(
+    input: &ErrorResponse,
+    http_response_variants: &[TokenStream2],
+) -> TokenStream2 {
+    if let Some(transformer_fn) = input.transform_response() {
+        quote! {
+            let (builder, _, _, message) = match &self {
+                #(#http_response_variants),*
+            };
+
+            let transformed: ::actix_web::HttpResponse // type checking.
+                = #transformer_fn(builder, message);
+
+            transformed
+        }
+    } else if input
+        .response_formats()
+        .is_empty()
+        && input.has_html_templates()
+    {
+        quote! {
+            let (mut builder, status, _, message) = match &self {
+                #(#http_response_variants),*
+            };
+
+            match self.__failwrap_html_template(status, &message) {
+                ::std::option::Option::Some(page) => builder
+                    .content_type("text/html; charset=utf-8")
+                    .body(page),
+                ::std::option::Option::None => builder.body(message),
+            }
+        }
+    } else if input
+        .response_formats()
+        .is_empty()
+    {
+        quote! {
+            let (mut builder, _, _, message) = match &self {
+                #(#http_response_variants),*
+            };
+
+            builder.body(message)
+        }
+    } else {
+        let formatted_response = formatted_response(input);
+
+        quote! {
+            let (mut builder, status, code, message) = match &self {
+                #(#http_response_variants),*
+            };
+
+            #formatted_response
+        }
+    }
+}
+
 /// **`error_response_output()`**
 ///
 /// Generates the [`ErrorResponse`] macro output.
@@ -46,27 +447,7 @@ pub fn error_response_output(input: &ErrorResponse) -> TokenStream2 {
                 .unwrap_or(input.default_status_code());
 
             let variant_head = variant_match_head(variant);
-
-            let mut http_response_variant = variant_head.clone();
-            let http_response_tokens = quote! {
-                ::actix_web::HttpResponse::#status_code()
-            };
-
-            http_response_variant.append_all(
-                if let Some(transformer_fn) = input.transform_response() {
-                    quote! {{
-                        let transformed: ::actix_web::HttpResponse // type checking.
-                            = #transformer_fn(#http_response_tokens, self.to_string());
-
-                        transformed
-                    }}
-                } else {
-                    quote! {
-                        #http_response_tokens
-                            .body(self.to_string())
-                    }
-                },
-            );
+            let http_response_variant = http_response_variant(variant, status_code);
 
             let mut error_variant = variant_head.clone();
             let error_variant_ident = format_ident!("Error{status_code}");
@@ -77,15 +458,55 @@ pub fn error_response_output(input: &ErrorResponse) -> TokenStream2 {
         })
         .collect::<(Vec<_>, Vec<_>)>();
 
+    let http_response = http_response_body(input, &http_response_variants);
+
+    let language_negotiation = language_negotiation(input);
+    let format_negotiation = if input
+        .response_formats()
+        .is_empty()
+    {
+        quote! {}
+    } else {
+        format_negotiation(input)
+    };
+    let format_helpers = format_helpers(input);
+    let format_jsonapi_source = format_jsonapi_source(input);
+    let format_html_template = format_html_template(input);
+    let format_envelope = format_envelope(input);
+    let extractor_fallback = extractor_fallback(input);
+    let extractor_error_table = extractor_error_table(input);
+    let variant_resolver = variant_resolver(input);
+
     quote! {
+        impl #enum_name {
+            #[doc(hidden)]
+            #[allow(unused_variables)]
+            pub fn __failwrap_response(
+                self,
+                request: ::std::option::Option<&::actix_web::HttpRequest>
+            ) -> ::actix_web::HttpResponse {
+                #http_response
+            }
+
+            #language_negotiation
+            #format_negotiation
+            #format_helpers
+            #format_jsonapi_source
+            #format_html_template
+            #format_envelope
+            #extractor_fallback
+        }
+
         impl ::std::convert::Into<::actix_web::HttpResponse> for #enum_name {
             fn into(self) -> ::actix_web::HttpResponse {
-                match self {
-                    #(#http_response_variants),*
-                }
+                self.__failwrap_response(::std::option::Option::None)
             }
         }
 
+        #extractor_error_table
+
+        #variant_resolver
+
         impl ::std::convert::Into<::actix_web::Error> for #enum_name {
             fn into(self) -> ::actix_web::Error {
                 match self {
@@ -4,4 +4,8 @@
 //! for each exported macro generation.
 
 pub mod error_response;
+pub mod proof_controller;
 pub mod proof_route;
+pub mod proof_scope;
+pub mod response_format;
+pub mod route_registry;
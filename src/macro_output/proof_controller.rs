@@ -0,0 +1,152 @@
+//! [`proof_controller`] Output Module
+//!
+//! Declares the macro generation functions,
+//! abstracting the macro generation instead
+//! of declaring it into the main module.
+//!
+//! [`proof_controller`]: crate::proof_controller
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+use crate::macro_input::proof_controller::{ProofControllerBody, ProofControllerMeta};
+use crate::macro_output::proof_route::{handler_wrapper, path_field_checks, route_resources};
+use crate::macro_output::route_registry::{registry_route, registry_submission};
+
+/// **`proof_controller_output()`**
+///
+/// Generates the [`proof_controller`] macro output.
+///
+/// [`proof_controller`]: crate::proof_controller
+pub fn proof_controller_output(
+    meta: &ProofControllerMeta,
+    body: &ProofControllerBody,
+) -> TokenStream2 {
+    let prefix = meta.prefix();
+    let self_ty = body.self_ty();
+    let item_impl = body.item_impl();
+    // resources are named after the controller so methods don't collide.
+    let controller_name = quote! { #self_ty }
+        .to_string()
+        .replace(' ', "");
+
+    let (wrappers, resources) = body
+        .routes()
+        .iter()
+        .map(|(route_meta, route_body)| {
+            let method_name = route_body.name();
+            let controller = route_body
+                .receiver()
+                .map(|_| self_ty);
+            let cfg_attributes = route_body
+                .cfg_attributes()
+                .collect::<Vec<_>>();
+
+            let wrapper = handler_wrapper(
+                route_meta,
+                route_body,
+                &quote! {},
+                &quote! { <#self_ty>::#method_name },
+                controller,
+            );
+            let resources = route_resources(
+                route_meta,
+                route_body,
+                &format!("{controller_name}::{method_name}"),
+            );
+
+            (
+                quote! {
+                    #(#cfg_attributes)*
+                    #wrapper
+                },
+                resources
+                    .iter()
+                    .map(|resource| {
+                        quote! {
+                            #(#cfg_attributes)*
+                            let __scope = __scope.service(#resource);
+                        }
+                    })
+                    .collect::<TokenStream2>(),
+            )
+        })
+        .collect::<(Vec<_>, Vec<_>)>();
+
+    let path_field_checks = body
+        .routes()
+        .iter()
+        .map(|(route_meta, route_body)| {
+            path_field_checks(route_meta, route_body, meta.prefix_segments())
+        })
+        .collect::<TokenStream2>();
+    let doc = format!(" Registers the routes of this controller under `{prefix}`.");
+    let submission = controller_submission(meta, body);
+
+    quote! {
+        #item_impl
+
+        impl #self_ty {
+            #[doc = #doc]
+            #[allow(deprecated)]
+            pub fn configure(__config: &mut ::actix_web::web::ServiceConfig) {
+                #(#wrappers)*
+
+                let __scope = ::actix_web::Scope::new(#prefix);
+                #(#resources)*
+
+                __config.service(__scope);
+            }
+        }
+
+        #path_field_checks
+
+        #submission
+    }
+}
+
+/// **`controller_submission()`**
+///
+/// Generates the route registry submission of the controller
+/// `configure` function if `auto_register = true` was declared.
+fn controller_submission(meta: &ProofControllerMeta, body: &ProofControllerBody) -> TokenStream2 {
+    let prefix = meta.prefix();
+    let self_ty = body.self_ty();
+    let item_impl = body.item_impl();
+
+    if meta
+        .auto_register()
+        .unwrap_or(false)
+    {
+        let routes = body
+            .routes()
+            .iter()
+            .flat_map(|(route_meta, route_body)| {
+                route_meta
+                    .method_paths()
+                    .map(|(method, path)| {
+                        registry_route(
+                            method,
+                            &format!("{prefix}{path}"),
+                            route_body.cfg_attributes(),
+                        )
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        registry_submission(
+            &quote! { #self_ty }.to_string(),
+            item_impl
+                .attrs
+                .iter()
+                .filter(|attr| {
+                    attr.path()
+                        .is_ident("cfg")
+                }),
+            &routes,
+            &quote! { <#self_ty>::configure(__config); },
+        )
+    } else {
+        quote! {}
+    }
+}
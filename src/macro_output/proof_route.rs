@@ -6,10 +6,76 @@
 //!
 //! [`proof_route`]: crate::proof_route
 
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
+use syn::{Expr, Ident, LitByteStr, LitStr, PathArguments, Type, TypePath, parse_str};
 
-use crate::macro_input::proof_route::{ProofRouteBody, ProofRouteMeta};
+use crate::helpers::http_methods::standard_method;
+use crate::helpers::semantics::{
+    has_option_semantics,
+    has_path_semantics,
+    has_payload_semantics,
+    is_primitive_type,
+};
+use crate::macro_input::proof_route::{
+    DESERIALIZE_CATEGORIES,
+    ErrorOverride,
+    ParameterSource,
+    ProofRouteBody,
+    ProofRouteMeta,
+    ProofRouteParameter,
+    ValidationBackend,
+};
+use crate::macro_output::route_registry::{registry_route, registry_submission};
+
+/// **`handler_placement()`**
+///
+/// Generates the original handler function, either nested
+/// in the generated service or exposed next to it when
+/// `expose_inner` is declared. Returns the nested function,
+/// the exposed function and the name the handler is called by.
+fn handler_placement<'a>(
+    meta: &'a ProofRouteMeta,
+    body: &'a ProofRouteBody,
+) -> (TokenStream2, TokenStream2, &'a Ident) {
+    let handler_name = body.name();
+
+    let Some(inner_name) = meta.expose_inner() else {
+        let function = body.function();
+
+        return (
+            quote! {
+                #[doc(hidden)]
+                #function
+            },
+            quote! {},
+            handler_name,
+        );
+    };
+
+    let mut function = body
+        .function()
+        .clone();
+    let cfg_attributes = body.cfg_attributes();
+    let doc = format!(" The [`{handler_name}`] route handler function.");
+
+    function
+        .sig
+        .ident = inner_name.clone();
+    function.vis = body
+        .vis()
+        .clone();
+
+    (
+        quote! {},
+        quote! {
+            #(#cfg_attributes)*
+            #[doc = #doc]
+            #function
+        },
+        inner_name,
+    )
+}
 
 /// **`proof_route_output()`**
 ///
@@ -17,71 +83,980 @@ use crate::macro_input::proof_route::{ProofRouteBody, ProofRouteMeta};
 ///
 /// [`proof_route`]: crate::proof_route
 pub fn proof_route_output(meta: &ProofRouteMeta, body: &ProofRouteBody) -> TokenStream2 {
-    let http_method = format_ident!(
-        "{}",
-        meta.method()
-            .to_lowercase()
-    );
-    let http_path = meta.path();
+    let handler_name = body.name();
+    let handler_vis = body.vis();
+    let handler_attributes = body.attributes();
+    let handler_cfg_attributes = body.cfg_attributes();
+    let (handler_function, exposed_function, handler_call) = handler_placement(meta, body);
+
+    let where_clause = &body
+        .function()
+        .sig
+        .generics
+        .where_clause;
+    let handler_wrapper =
+        handler_wrapper(meta, body, &handler_function, &quote! { #handler_call }, None);
+    let resources = route_resources(meta, body, &handler_name.to_string());
+
+    let service = if body.is_generic() {
+        let impl_generics = &body
+            .function()
+            .sig
+            .generics;
+
+        quote! {
+            #(#handler_attributes)*
+            #handler_vis fn #handler_name #impl_generics() -> impl ::actix_web::dev::HttpServiceFactory
+            #where_clause
+            {
+                #handler_wrapper
+
+                ::std::vec![#(#resources),*]
+            }
+        }
+    } else {
+        quote! {
+            #(#handler_attributes)*
+            #[allow(non_camel_case_types)]
+            #handler_vis struct #handler_name;
+
+            #(#handler_cfg_attributes)*
+            #[allow(deprecated)]
+            impl ::actix_web::dev::HttpServiceFactory for #handler_name {
+                fn register(self, __config: &mut ::actix_web::dev::AppService) {
+                    #handler_wrapper
+
+                    #(::actix_web::dev::HttpServiceFactory::register(#resources, __config);)*
+                }
+            }
+        }
+    };
 
+    // generic handlers need concrete arguments to be registered.
+    let submission = if !body.is_generic()
+        && meta
+            .auto_register()
+            .unwrap_or(false)
+    {
+        registry_submission(
+            &handler_name.to_string(),
+            body.cfg_attributes(),
+            &meta
+                .method_paths()
+                .map(|(method, path)| registry_route(method, path, []))
+                .collect::<Vec<_>>(),
+            &quote! { __config.service(#handler_name); },
+        )
+    } else {
+        quote! {}
+    };
+
+    let path_field_checks = path_field_checks(meta, body, &[]);
+
+    quote! {
+        #service
+
+        #exposed_function
+
+        #path_field_checks
+
+        #submission
+    }
+}
+
+/// **`route_resources()`**
+///
+/// Generates an `actix_web::Resource` expression for each
+/// route declaration, routing the requests matching any of
+/// its methods to the handler wrapper. Only the first
+/// resource is named, after the `default_name` if no
+/// `name = ".."` was declared.
+pub fn route_resources(
+    meta: &ProofRouteMeta,
+    body: &ProofRouteBody,
+    default_name: &str,
+) -> Vec<TokenStream2> {
     let handler_name = body.name();
-    let handler_function = body.function();
-    let (return_success, return_error) = body.return_result_semantics();
+    let resource_name = meta
+        .name()
+        .map_or_else(|| default_name.to_string(), LitStr::value);
+    let guards = meta.guards();
+    let wraps = meta.wraps();
+    let (_, ty_generics, _) = body
+        .function()
+        .sig
+        .generics
+        .split_for_impl();
+    let turbofish = ty_generics.as_turbofish();
 
-    let parameters = body.parameters();
-    let parameters = parameters
+    meta.routes()
         .iter()
         .enumerate()
-        .fold(Vec::with_capacity(parameters.len()), |mut acc, (idx, curr)| {
-            let var_name = format_ident!("__{idx}");
-            let ty = curr.ty();
-            let error_override = if let Some(error) = curr.error_override() {
-                quote! { Err(_) => {
-                    return #return_error::#error.into();
-                } }
+        .map(|(idx, route)| {
+            let http_path = route.path();
+            let name = (idx == 0).then(|| quote! { .name(#resource_name) });
+            let mut methods = route
+                .methods()
+                .iter()
+                .map(|method| {
+                    let http_method = http_method(method);
+
+                    quote! { ::actix_web::guard::Method(#http_method) }
+                });
+            let first_method = methods.next();
+
+            quote! {
+                ::actix_web::Resource::new(#http_path)
+                    #name
+                    .guard(::actix_web::guard::Any(#first_method)#(.or(#methods))*)
+                    #(.guard(::actix_web::guard::fn_guard(#guards)))*
+                    .to(#handler_name #turbofish)
+                    #(.wrap(#wraps))*
+            }
+        })
+        .collect()
+}
+
+/// **`path_field_checks()`**
+///
+/// Generates a destructuring check for each `web::Path` struct
+/// extractor, so the segments of the `scope_prefix`, the provided
+/// prefix and the route path that are not fields of the struct
+/// fail to compile. Single segment routes are not checked as the
+/// type may be deserialized from a single value.
+pub fn path_field_checks(
+    meta: &ProofRouteMeta,
+    body: &ProofRouteBody,
+    prefix_segments: &[String],
+) -> TokenStream2 {
+    let generic_params = body
+        .function()
+        .sig
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+    let cfg_attributes = body
+        .cfg_attributes()
+        .collect::<Vec<_>>();
+
+    body.parameters()
+        .iter()
+        .filter_map(|parameter| has_path_semantics(parameter.ty()))
+        .filter(|inner| {
+            let Type::Path(TypePath { qself: None, path }) = inner else {
+                return false;
+            };
+
+            !is_primitive_type(inner)
+                && path
+                    .segments
+                    .iter()
+                    .all(|segment| {
+                        segment
+                            .arguments
+                            .is_none()
+                            && segment.ident != "Self"
+                    })
+                && !path
+                    .get_ident()
+                    .is_some_and(|ident| generic_params.contains(&ident))
+        })
+        .flat_map(|inner| {
+            let cfg_attributes = &cfg_attributes;
+
+            meta.routes()
+                .iter()
+                .filter_map(move |route| {
+                    let segments = meta.full_segments(prefix_segments, route);
+
+                    if segments.len() < 2 {
+                        return None;
+                    }
+
+                    let fields = segments
+                        .iter()
+                        .map(|segment| {
+                            parse_str::<Ident>(segment)
+                                .ok()
+                                .map(|field| {
+                                    Ident::new(
+                                        &field.to_string(),
+                                        route
+                                            .lit_str()
+                                            .span(),
+                                    )
+                                })
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+
+                    Some(quote! {
+                        #(#cfg_attributes)*
+                        const _: () = {
+                            #[allow(dead_code, unused_variables)]
+                            fn __path_fields(__value: #inner) {
+                                let #inner { #(#fields: _,)* .. } = __value;
+                            }
+                        };
+                    })
+                })
+        })
+        .collect()
+}
+
+/// **`parameter_extractor()`**
+///
+/// Generates the extraction of a handler parameter, either
+/// with its type as an extractor or looked up by its source
+/// and parsed with `FromStr`. Errors are replaced by the
+/// `#[error_override(..)]` of the parameter, otherwise by the
+/// route `extractor_error` or the `#[extractor_fallback(..)]`
+/// of the handler error.
+fn parameter_extractor(
+    idx: usize,
+    parameter: &ProofRouteParameter,
+    meta: &ProofRouteMeta,
+    return_error: &Type,
+) -> TokenStream2 {
+    let var_name = format_ident!("__{idx}");
+    let ty = parameter.ty();
+    let fallback_arm = fallback_response(parameter, return_error);
+    let route_arm = meta
+        .extractor_error()
+        .map_or_else(
+            || fallback_arm.clone(),
+            |extractor_error| override_response(extractor_error, return_error, &fallback_arm),
+        );
+    let error_arm = parameter
+        .error_override()
+        .map_or_else(
+            || route_arm.clone(),
+            |error_override| override_response(error_override, return_error, &route_arm),
+        );
+
+    let Some(source) = parameter.source() else {
+        if meta.path_to_error()
+            && let Some((payload, inner)) = has_payload_semantics(ty)
+        {
+            return path_to_error_extractor(&var_name, ty, payload, inner, &error_arm);
+        }
+
+        return quote! {
+            let #var_name: #ty = match
+            <#ty as ::actix_web::FromRequest>::from_request(&__request, &mut __payload).await {
+                Ok(value) => value,
+                Err(__error) => {
+                    #[allow(unused_variables)]
+                    let err: ::actix_web::Error = __error.into();
+                    #error_arm
+                }
+            };
+        };
+    };
+
+    let name = source.name();
+    // the lookups yield `Err` for values that can't be read as text,
+    // or for a query string that can't be parsed at all.
+    let lookup = match source {
+        ParameterSource::Path(_) => quote! {
+            __request
+                .match_info()
+                .get(#name)
+                .map(|value| ::std::result::Result::Ok(value.to_string()))
+        },
+        ParameterSource::Query(_) => quote! {
+            ::actix_web::web::Query::<::std::collections::HashMap<
+                ::std::string::String,
+                ::std::string::String
+            >>::from_query(__request.query_string())
+                .map_or(::std::option::Option::Some(::std::result::Result::Err(())), |query| {
+                    query
+                        .get(#name)
+                        .cloned()
+                        .map(::std::result::Result::Ok)
+                })
+        },
+        ParameterSource::Header(_) => quote! {
+            __request
+                .headers()
+                .get(#name)
+                .map(|value| value.to_str().map(::std::string::ToString::to_string).map_err(|_| ()))
+        },
+        ParameterSource::Cookie(_) => quote! {
+            __request
+                .cookie(#name)
+                .map(|cookie| ::std::result::Result::Ok(cookie.value().to_string()))
+        },
+    };
+
+    let missing = format!("Missing {} {name}.", source.kind());
+    let invalid = format!("Invalid {} {name}.", source.kind());
+    let (value_ty, missing_value, parsed) = match has_option_semantics(ty) {
+        Some(inner) => (
+            inner,
+            quote! { Ok(::std::option::Option::None) },
+            quote! { .map(::std::option::Option::Some) },
+        ),
+        None => (ty, quote! { Err(#missing) }, quote! {}),
+    };
+    let error = if matches!(source, ParameterSource::Path(_)) {
+        quote! { ErrorNotFound }
+    } else {
+        quote! { ErrorBadRequest }
+    };
+    quote! {
+        let #var_name: #ty = match match #lookup {
+            ::std::option::Option::Some(value) => value
+                .and_then(|value| value.parse::<#value_ty>().map_err(|_| ()))
+                #parsed
+                .map_err(|()| #invalid),
+            ::std::option::Option::None => #missing_value,
+        } {
+            Ok(value) => value,
+            Err(error) => {
+                #[allow(unused_variables)]
+                let err = ::actix_web::error::#error(error);
+                #error_arm
+            }
+        };
+    }
+}
+
+/// **`path_to_error_extractor()`**
+///
+/// Generates the extraction of a `Json`, `Query` or `Form`
+/// parameter deserialized with `serde_path_to_error`, binding
+/// the failing field `path` built from the path segments, the
+/// `expected` type, taken from the `, expected ..` part of
+/// the serde message, and the `problem` alongside `err` for
+/// the error arm.
+fn path_to_error_extractor(
+    var_name: &Ident,
+    ty: &Type,
+    payload: &str,
+    inner: &Type,
+    error_arm: &TokenStream2,
+) -> TokenStream2 {
+    let (native_error, inner_error) = match payload {
+        "Json" => (
+            quote! { ::actix_web::error::JsonPayloadError::Deserialize },
+            quote! { ::serde_json::Error },
+        ),
+        "Query" => (
+            quote! { ::actix_web::error::QueryPayloadError::Deserialize },
+            quote! { ::serde::de::value::Error },
+        ),
+        _ => (
+            quote! { ::actix_web::error::UrlencodedError::Parse },
+            quote! { ::serde::de::value::Error },
+        ),
+    };
+    let friendly_error = quote! {
+        |__error: ::serde_path_to_error::Error<#inner_error>| {
+            let path = __error
+                .path()
+                .iter()
+                .map(|segment| match segment {
+                    ::serde_path_to_error::Segment::Seq { index } => index.to_string(),
+                    ::serde_path_to_error::Segment::Map { key } => key.clone(),
+                    ::serde_path_to_error::Segment::Enum { variant } => variant.clone(),
+                    ::serde_path_to_error::Segment::Unknown => "?".to_owned(),
+                })
+                .collect::<::std::vec::Vec<_>>()
+                .join(".");
+            let problem = __error
+                .into_inner()
+                .to_string();
+            let expected = problem
+                .split_once(", expected ")
+                .map(|(_, expected)| expected.to_owned());
+            let err = ::actix_web::Error::from(#native_error(
+                <#inner_error as ::serde::de::Error>::custom(if path.is_empty() {
+                    problem.clone()
+                } else {
+                    ::std::format!("{path}: {problem}")
+                })
+            ));
+
+            (err, path, expected, problem)
+        }
+    };
+    let extraction_error = quote! {
+        |__error| {
+            let err: ::actix_web::Error = __error.into();
+            let problem = err.to_string();
+
+            (err, ::std::string::String::new(), ::std::option::Option::None, problem)
+        }
+    };
+    let deserialized = match payload {
+        "Json" => quote! {
+            <::actix_web::web::Json<::serde_json::Value> as ::actix_web::FromRequest>
+                ::from_request(&__request, &mut __payload)
+                .await
+                .map_err(#extraction_error)
+                .and_then(|__value| {
+                    ::serde_path_to_error::deserialize::<_, #inner>(__value.into_inner())
+                        .map_err(#friendly_error)
+                })
+        },
+        "Query" => quote! {
+            ::serde_path_to_error::deserialize::<_, #inner>(::serde_urlencoded::Deserializer::new(
+                ::form_urlencoded::parse(__request.query_string().as_bytes())
+            ))
+                .map_err(#friendly_error)
+        },
+        _ => quote! {
+            <::actix_web::web::Form<::std::vec::Vec<(
+                ::std::string::String,
+                ::std::string::String
+            )>> as ::actix_web::FromRequest>
+                ::from_request(&__request, &mut __payload)
+                .await
+                .map_err(#extraction_error)
+                .and_then(|__value| {
+                    let __encoded = ::serde_urlencoded::to_string(__value.into_inner())
+                        .map_err(::actix_web::error::UrlencodedError::Serialize)
+                        .map_err(#extraction_error)?;
+
+                    ::serde_path_to_error::deserialize::<_, #inner>(
+                        ::serde_urlencoded::Deserializer::new(
+                            ::form_urlencoded::parse(__encoded.as_bytes())
+                        )
+                    )
+                        .map_err(#friendly_error)
+                })
+        },
+    };
+    let payload = format_ident!("{payload}");
+
+    quote! {
+        let #var_name: #ty = match #deserialized {
+            ::std::result::Result::Ok(value) => ::actix_web::web::#payload(value),
+            #[allow(unused_variables)]
+            ::std::result::Result::Err((err, path, expected, problem)) => { #error_arm }
+        };
+    }
+}
+
+/// **`parameter_validation()`**
+///
+/// Generates the validation of an extracted parameter
+/// annotated with `#[validate(..)]`, rendering failures
+/// as the `on_error` variant or a `422 Unprocessable Entity`
+/// with the `err`, `fields` and `req` bindings available.
+fn parameter_validation(
+    idx: usize,
+    parameter: &ProofRouteParameter,
+    return_error: &Type,
+) -> TokenStream2 {
+    let Some(validation) = parameter.validation() else {
+        return quote! {};
+    };
+
+    let var_name = format_ident!("__{idx}");
+    let (validate, fields) = match validation.backend() {
+        ValidationBackend::Validator => (
+            quote! {
+                use ::validator::Validate as _;
+                #var_name.validate()
+            },
+            quote! {
+                err.field_errors()
+                    .into_iter()
+                    .map(|(field, errors)| {
+                        (
+                            field.to_string(),
+                            errors
+                                .iter()
+                                .map(|error| {
+                                    error
+                                        .message
+                                        .as_ref()
+                                        .unwrap_or(&error.code)
+                                        .to_string()
+                                })
+                                .collect()
+                        )
+                    })
+                    .collect()
+            },
+        ),
+        ValidationBackend::Garde => (
+            quote! {
+                use ::garde::Validate as _;
+                #var_name.validate()
+            },
+            quote! {
+                err.iter()
+                    .fold(::std::collections::BTreeMap::new(), |mut fields, (path, error)| {
+                        ::std::collections::BTreeMap::<_, ::std::vec::Vec<_>>::entry(
+                            &mut fields,
+                            path.to_string()
+                        )
+                            .or_default()
+                            .push(error.message().to_string());
+                        fields
+                    })
+            },
+        ),
+    };
+    let on_error = validation
+        .on_error()
+        .map_or_else(
+            || {
+                quote! {
+                    return ::actix_web::HttpResponse::from_error(
+                        ::actix_web::error::ErrorUnprocessableEntity(err.to_string())
+                    );
+                }
+            },
+            |variant| {
+                let variant = resolved_variant(return_error, variant);
+
+                quote! {
+                    return #variant
+                        .__failwrap_response(::std::option::Option::Some(&__request));
+                }
+            },
+        );
+
+    quote! {
+        if let ::std::result::Result::Err(err) = { #validate } {
+            #[allow(unused_variables)]
+            let fields: ::std::collections::BTreeMap<
+                ::std::string::String,
+                ::std::vec::Vec<::std::string::String>
+            > = #fields;
+            #[allow(unused_variables)]
+            let req: &::actix_web::HttpRequest = &__request;
+
+            #on_error
+        }
+    }
+}
+
+/// **`fallback_response()`**
+///
+/// Generates the early return for extractor errors without
+/// any override, rendering the variant mapped to the extractor
+/// by the handler error table, the `#[extractor_fallback(..)]`
+/// variant or the original error otherwise.
+fn fallback_response(parameter: &ProofRouteParameter, return_error: &Type) -> TokenStream2 {
+    let table_lookup = extractor_table_lookup(&extractor_key_type(parameter), return_error);
+
+    quote! {
+        let __mapped: ::std::option::Option<
+            fn(::actix_web::Error, &::actix_web::HttpRequest) -> #return_error
+        > = #table_lookup;
+
+        if let ::std::option::Option::Some(__variant) = __mapped {
+            return __variant(err, &__request)
+                .__failwrap_response(::std::option::Option::Some(&__request));
+        }
+
+        return match #return_error::__failwrap_extractor_fallback(err, &__request) {
+            ::std::result::Result::Ok(__error) => __error
+                .__failwrap_response(::std::option::Option::Some(&__request)),
+            ::std::result::Result::Err(err) => ::actix_web::HttpResponse::from_error(err),
+        };
+    }
+}
+
+/// **`extractor_key_type()`**
+///
+/// The type the `#[extractor_errors(..)]` table of the
+/// handler error is looked up with, the extractor type
+/// itself or the extractor the parameter source stands for.
+fn extractor_key_type(parameter: &ProofRouteParameter) -> TokenStream2 {
+    match parameter.source() {
+        None => {
+            let ty = parameter.ty();
+
+            quote! { #ty }
+        },
+        Some(ParameterSource::Path(_)) => quote! { ::actix_web::web::Path<()> },
+        Some(ParameterSource::Query(_)) => quote! { ::actix_web::web::Query<()> },
+        Some(ParameterSource::Header(_)) => quote! { ::actix_web::web::Header<()> },
+        Some(ParameterSource::Cookie(_)) => quote! { ::actix_web::cookie::Cookie<'static> },
+    }
+}
+
+/// **`extractor_table_lookup()`**
+///
+/// Generates an expression resolving the variant constructor
+/// mapped to the extractor type by the handler error table,
+/// `None` if the table has no `Index` implementation for it.
+/// The lookup relies on auto-ref method resolution, which
+/// prefers the mapped probe and falls back to the unmapped
+/// one, so it's settled at compile time.
+fn extractor_table_lookup(key_type: &TokenStream2, return_error: &Type) -> TokenStream2 {
+    quote! {{
+        struct __Probe<'a, __X, __T>(&'a __T, ::std::marker::PhantomData<__X>);
+
+        trait __Mapped<__E> {
+            fn __failwrap_lookup(&self)
+                -> ::std::option::Option<fn(::actix_web::Error, &::actix_web::HttpRequest) -> __E>;
+        }
+
+        impl<__X, __T, __E> __Mapped<__E> for &&__Probe<'_, __X, __T>
+        where
+            __T: ::std::ops::Index<
+                ::std::marker::PhantomData<__X>,
+                Output = fn(::actix_web::Error, &::actix_web::HttpRequest) -> __E
+            >
+        {
+            fn __failwrap_lookup(&self)
+                -> ::std::option::Option<fn(::actix_web::Error, &::actix_web::HttpRequest) -> __E> {
+                ::std::option::Option::Some(*::std::ops::Index::index(self.0, ::std::marker::PhantomData))
+            }
+        }
+
+        trait __Unmapped<__E> {
+            fn __failwrap_lookup(&self)
+                -> ::std::option::Option<fn(::actix_web::Error, &::actix_web::HttpRequest) -> __E>;
+        }
+
+        impl<__X, __T, __E> __Unmapped<__E> for &__Probe<'_, __X, __T> {
+            fn __failwrap_lookup(&self)
+                -> ::std::option::Option<fn(::actix_web::Error, &::actix_web::HttpRequest) -> __E> {
+                ::std::option::Option::None
+            }
+        }
+
+        (&&&__Probe::<#key_type, _>(
+            &#return_error::__FAILWRAP_EXTRACTOR_ERRORS,
+            ::std::marker::PhantomData
+        ))
+            .__failwrap_lookup()
+    }}
+}
+
+/// **`resolved_variant()`**
+///
+/// Generates the construction of a variant expression
+/// through the macro the `ErrorResponse` derive generates
+/// next to the error type, which is named after it and
+/// resolves the variant at macro time.
+fn resolved_variant(return_error: &Type, variant: &Expr) -> TokenStream2 {
+    let Type::Path(TypePath { qself: None, path }) = return_error else {
+        return quote! { #return_error::#variant };
+    };
+    let mut macro_path = path.clone();
+
+    if let Some(segment) = macro_path
+        .segments
+        .last_mut()
+    {
+        segment.arguments = PathArguments::None;
+    }
+
+    quote! { #macro_path!(#return_error => #variant) }
+}
+
+/// **`override_response()`**
+///
+/// Generates the early return of an `#[error_override(..)]`
+/// response, the original error must be bound as `err` and
+/// the variant expressions may also use the `req` binding.
+/// The `unmatched` tokens handle the errors of kinds that
+/// no arm matches.
+fn override_response(
+    error_override: &ErrorOverride,
+    return_error: &Type,
+    unmatched: &TokenStream2,
+) -> TokenStream2 {
+    match error_override {
+        ErrorOverride::Variant(variant) => {
+            let variant = resolved_variant(return_error, variant);
+
+            quote! {
+                #[allow(unused_variables)]
+                let req: &::actix_web::HttpRequest = &__request;
+
+                return #variant
+                    .__failwrap_response(::std::option::Option::Some(&__request));
+            }
+        },
+        ErrorOverride::With(function) => quote! {
+            let __error: #return_error = (#function)(err, &__request);
+
+            return __error.__failwrap_response(::std::option::Option::Some(&__request));
+        },
+        ErrorOverride::Kinds(arms) => {
+            let error_kind = error_kind();
+            let error_category = error_category();
+            let arms = arms
+                .iter()
+                .map(|arm| {
+                    let variant = resolved_variant(return_error, arm.variant());
+                    let pattern = if arm
+                        .kinds()
+                        .is_empty()
+                    {
+                        quote! { _ }
+                    } else {
+                        let (categories, kinds) = arm
+                            .kinds()
+                            .iter()
+                            .map(ToString::to_string)
+                            .partition::<Vec<_>, _>(|kind| {
+                                DESERIALIZE_CATEGORIES.contains(&kind.as_str())
+                            });
+                        let patterns = (!kinds.is_empty())
+                            .then(|| quote! { (::std::option::Option::Some(#(#kinds)|*), _) })
+                            .into_iter()
+                            .chain((!categories.is_empty()).then(
+                                || quote! { (_, ::std::option::Option::Some(#(#categories)|*)) },
+                            ));
+
+                        quote! { #(#patterns)|* }
+                    };
+
+                    quote! {
+                        #pattern => return #variant
+                            .__failwrap_response(::std::option::Option::Some(&__request)),
+                    }
+                })
+                .collect::<Vec<_>>();
+            let unmatched_arm = if error_override.has_wildcard() {
+                quote! {}
             } else {
-                quote! { Err(error) => return error.into() }
+                quote! { _ => { #unmatched } }
             };
 
-            acc.push(quote! {
-                let #var_name: #ty = match
-                <#ty as ::actix_web::FromRequest>::from_request(&__request, &mut __payload).await {
-                    Ok(value) => value,
-                    #error_override
-                };
-            });
+            quote! {
+                #[allow(unused_variables)]
+                let req: &::actix_web::HttpRequest = &__request;
+                let __kind: ::std::option::Option<&'static str> = #error_kind;
+                let __category: ::std::option::Option<&'static str> = #error_category;
 
-            acc
-        });
+                match (__kind, __category) {
+                    #(#arms)*
+                    #unmatched_arm
+                }
+            }
+        },
+    }
+}
+
+/// **`error_kind()`**
+///
+/// Generates the expression that obtains the kind of the
+/// `err` binding, if it is a `JsonPayloadError`,
+/// `UrlencodedError`, `QueryPayloadError` or `PathError`.
+fn error_kind() -> TokenStream2 {
+    quote! {
+        if let ::std::option::Option::Some(error) = err
+            .as_error::<::actix_web::error::JsonPayloadError>()
+        {
+            match error {
+                ::actix_web::error::JsonPayloadError::OverflowKnownLength { .. }
+                | ::actix_web::error::JsonPayloadError::Overflow { .. } => {
+                    ::std::option::Option::Some("Overflow")
+                },
+                ::actix_web::error::JsonPayloadError::ContentType => {
+                    ::std::option::Option::Some("ContentType")
+                },
+                ::actix_web::error::JsonPayloadError::Deserialize(_) => {
+                    ::std::option::Option::Some("Deserialize")
+                },
+                ::actix_web::error::JsonPayloadError::Serialize(_) => {
+                    ::std::option::Option::Some("Serialize")
+                },
+                ::actix_web::error::JsonPayloadError::Payload(_) => {
+                    ::std::option::Option::Some("Payload")
+                },
+                _ => ::std::option::Option::None,
+            }
+        } else if let ::std::option::Option::Some(error) = err
+            .as_error::<::actix_web::error::UrlencodedError>()
+        {
+            match error {
+                ::actix_web::error::UrlencodedError::Chunked => {
+                    ::std::option::Option::Some("Chunked")
+                },
+                ::actix_web::error::UrlencodedError::Overflow { .. } => {
+                    ::std::option::Option::Some("Overflow")
+                },
+                ::actix_web::error::UrlencodedError::UnknownLength => {
+                    ::std::option::Option::Some("UnknownLength")
+                },
+                ::actix_web::error::UrlencodedError::ContentType => {
+                    ::std::option::Option::Some("ContentType")
+                },
+                ::actix_web::error::UrlencodedError::Parse(_) => {
+                    ::std::option::Option::Some("Parse")
+                },
+                ::actix_web::error::UrlencodedError::Encoding => {
+                    ::std::option::Option::Some("Encoding")
+                },
+                ::actix_web::error::UrlencodedError::Serialize(_) => {
+                    ::std::option::Option::Some("Serialize")
+                },
+                ::actix_web::error::UrlencodedError::Payload(_) => {
+                    ::std::option::Option::Some("Payload")
+                },
+                _ => ::std::option::Option::None,
+            }
+        } else if let ::std::option::Option::Some(error) = err
+            .as_error::<::actix_web::error::QueryPayloadError>()
+        {
+            match error {
+                ::actix_web::error::QueryPayloadError::Deserialize(_) => {
+                    ::std::option::Option::Some("Deserialize")
+                },
+                _ => ::std::option::Option::None,
+            }
+        } else if let ::std::option::Option::Some(error) = err
+            .as_error::<::actix_web::error::PathError>()
+        {
+            match error {
+                ::actix_web::error::PathError::Deserialize(_) => {
+                    ::std::option::Option::Some("Deserialize")
+                },
+                _ => ::std::option::Option::None,
+            }
+        } else {
+            ::std::option::Option::None
+        }
+    }
+}
+
+/// **`error_category()`**
+///
+/// Generates the expression that obtains the category of
+/// the `err` binding, if it is a `JsonPayloadError` failing
+/// to deserialize, telling broken syntax and truncated
+/// bodies apart from valid JSON with the wrong shape.
+fn error_category() -> TokenStream2 {
+    quote! {
+        match err.as_error::<::actix_web::error::JsonPayloadError>() {
+            ::std::option::Option::Some(
+                ::actix_web::error::JsonPayloadError::Deserialize(error)
+            ) if error.is_syntax() => ::std::option::Option::Some("Syntax"),
+            ::std::option::Option::Some(
+                ::actix_web::error::JsonPayloadError::Deserialize(error)
+            ) if error.is_data() => ::std::option::Option::Some("Data"),
+            ::std::option::Option::Some(
+                ::actix_web::error::JsonPayloadError::Deserialize(error)
+            ) if error.is_eof() => ::std::option::Option::Some("Eof"),
+            _ => ::std::option::Option::None,
+        }
+    }
+}
+
+/// **`http_method()`**
+///
+/// Generates the `actix_web::http::Method` for a route method,
+/// using the constants for standard methods and parsing the
+/// extension methods, which were validated as tokens already.
+fn http_method(method: &str) -> TokenStream2 {
+    if let Some(standard) = standard_method(method) {
+        let standard = format_ident!("{standard}");
+
+        return quote! { ::actix_web::http::Method::#standard };
+    }
+
+    let bytes = LitByteStr::new(method.as_bytes(), Span::call_site());
+
+    quote! {
+        ::actix_web::http::Method::from_bytes(#bytes)
+            .expect("The method was validated as an RFC 7230 token.")
+    }
+}
+
+/// **`handler_wrapper()`**
+///
+/// Generates the function actix calls for each request,
+/// which runs the extractors, calls the original handler
+/// and converts its result into a response. The wrapper
+/// takes the same generics as the original handler, when
+/// a controller type is provided the handler receives it
+/// as `&self` from the `web::Data` in the app.
+pub fn handler_wrapper(
+    meta: &ProofRouteMeta,
+    body: &ProofRouteBody,
+    handler_function: &TokenStream2,
+    handler_call: &TokenStream2,
+    controller: Option<&Type>,
+) -> TokenStream2 {
+    let handler_name = body.name();
+    let return_error = body.return_error();
+    let generics = &body
+        .function()
+        .sig
+        .generics;
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let turbofish = ty_generics.as_turbofish();
+
+    let parameters = body
+        .parameters()
+        .iter()
+        .enumerate()
+        .map(|(idx, parameter)| {
+            let extractor = parameter_extractor(idx, parameter, meta, return_error);
+            let validation = parameter_validation(idx, parameter, return_error);
+
+            quote! {
+                #extractor
+                #validation
+            }
+        })
+        .collect::<Vec<_>>();
 
     let param_references = (0..parameters.len()).map(|idx| format_ident!("__{idx}"));
+    let (controller_extractor, controller_reference) = controller.map_or_else(
+        || (quote! {}, quote! {}),
+        |controller| {
+            (
+                quote! {
+                    let __self = match <::actix_web::web::Data<#controller> as ::actix_web::FromRequest>
+                        ::from_request(&__request, &mut __payload).await {
+                        Ok(value) => value,
+                        Err(error) => return error.into()
+                    };
+                },
+                quote! { &__self, },
+            )
+        },
+    );
 
     quote! {
-        #[::actix_web::#http_method(#http_path)]
-        async fn #handler_name(
+        async fn #handler_name #generics(
             __request: ::actix_web::HttpRequest,
             __payload: ::actix_web::web::Payload
-        ) -> impl ::actix_web::Responder {
+        ) -> ::actix_web::HttpResponse
+        #where_clause
+        {
             #[doc(hidden)]
             fn __validate_ret_type<
                 T: ::actix_web::Responder,
-                E: ::std::convert::Into<::actix_web::HttpResponse>>()
-            {}
-
-            __validate_ret_type::<#return_success, #return_error>();
+                E: ::std::convert::Into<::actix_web::HttpResponse>>(
+                result: ::std::result::Result<T, E>
+            ) -> ::std::result::Result<T, E> {
+                result
+            }
 
-            #[doc(hidden)]
             #handler_function
 
             #[allow(unused)]
             #[doc(hidden)]
             let mut __payload = __payload.into_inner();
 
+            #controller_extractor
             #(#parameters)*
 
-            match #handler_name(#(#param_references),*).await {
-                ::std::result::Result::Ok(result) => result,
-                ::std::result::Result::Err(error) => error.into()
+            match __validate_ret_type::<_, #return_error>(
+                #handler_call #turbofish(#controller_reference #(#param_references),*).await
+            ) {
+                ::std::result::Result::Ok(result) => ::actix_web::Responder::respond_to(
+                    result,
+                    &__request
+                )
+                    .map_into_boxed_body(),
+                ::std::result::Result::Err(error) => error
+                    .__failwrap_response(::std::option::Option::Some(&__request))
             }
         }
     }
@@ -0,0 +1,99 @@
+//! [`proof_scope`] Output Module
+//!
+//! Declares the macro generation functions,
+//! abstracting the macro generation instead
+//! of declaring it into the main module.
+//!
+//! [`proof_scope`]: crate::proof_scope
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ItemMod;
+
+use crate::macro_input::proof_scope::{ProofScopeBody, ProofScopeMeta, ScopeRegistration};
+use crate::macro_output::route_registry::{registry_route, registry_submission};
+
+/// **`proof_scope_output()`**
+///
+/// Generates the [`proof_scope`] macro output.
+///
+/// [`proof_scope`]: crate::proof_scope
+pub fn proof_scope_output(meta: &ProofScopeMeta, body: &ProofScopeBody) -> TokenStream2 {
+    let prefix = meta.prefix();
+
+    let registrations = body
+        .registrations()
+        .iter()
+        .map(|registration| match registration {
+            ScopeRegistration::Route(name, cfg_attributes) => quote! {
+                #(#cfg_attributes)*
+                let __scope = __scope.service(#name);
+            },
+            ScopeRegistration::Scope(name, cfg_attributes) => quote! {
+                #(#cfg_attributes)*
+                let __scope = __scope.configure(#name::configure);
+            },
+            ScopeRegistration::Controller(self_ty, cfg_attributes) => quote! {
+                #(#cfg_attributes)*
+                let __scope = __scope.configure(<#self_ty>::configure);
+            },
+        });
+
+    let doc = format!(" Registers the routes of this scope under `{prefix}`.");
+    let ItemMod {
+        attrs,
+        vis,
+        unsafety,
+        mod_token,
+        ident,
+        content,
+        ..
+    } = body.item_mod();
+    let items = content
+        .as_ref()
+        .map(|(_, items)| items.as_slice())
+        .unwrap_or_default();
+
+    let submission = if meta
+        .auto_register()
+        .unwrap_or(false)
+    {
+        let routes = body
+            .routes()
+            .iter()
+            .map(|route| registry_route(route.method(), route.path(), route.cfg_attributes()))
+            .collect::<Vec<_>>();
+
+        registry_submission(
+            &ident.to_string(),
+            attrs
+                .iter()
+                .filter(|attr| {
+                    attr.path()
+                        .is_ident("cfg")
+                }),
+            &routes,
+            &quote! { #ident::configure(__config); },
+        )
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #(#attrs)*
+        #vis #unsafety #mod_token #ident {
+            #(#items)*
+
+            #[doc = #doc]
+            #[allow(deprecated)]
+            pub fn configure(__config: &mut ::actix_web::web::ServiceConfig) {
+                let __scope = ::actix_web::Scope::new(#prefix);
+                #(#registrations)*
+
+                __config.service(__scope);
+            }
+        }
+
+        #submission
+    }
+}
@@ -0,0 +1,631 @@
+//! Response Format Output Module
+//!
+//! Declares the generation functions for the
+//! negotiated error bodies of the [`ErrorResponse`]
+//! macro, used when `#[response_format(..)]` is present.
+//!
+//! [`ErrorResponse`]: crate::ErrorResponse
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Fields as VariantFields, Index, Member};
+
+use crate::helpers::templates::TemplateSegment;
+use crate::macro_input::error_response::{ErrorResponse, ErrorResponseVariant, ResponseFormat};
+use crate::macro_output::error_response::{
+    field_binding,
+    variant_binding_head,
+    variant_match_head,
+};
+
+/// **`format_negotiation()`**
+///
+/// Generates a function that negotiates the response
+/// format from the request `Accept` header. The function
+/// returns the format name or None if the request doesn't
+/// accept any of the declared formats and those requests
+/// should be rejected. Media types with a zero quality are
+/// never picked, not even through a wildcard.
+pub fn format_negotiation(input: &ErrorResponse) -> TokenStream2 {
+    let (names, essences) = input
+        .response_formats()
+        .iter()
+        .map(|format| {
+            let essence = format
+                .content_type()
+                .split(';')
+                .next()
+                .unwrap_or_default();
+
+            (format.name(), essence)
+        })
+        .collect::<(Vec<_>, Vec<_>)>();
+
+    let not_acceptable = if input.reject_not_acceptable() {
+        quote! { ::std::option::Option::None }
+    } else {
+        quote! { ::std::option::Option::Some(FORMATS[0].0) }
+    };
+
+    quote! {
+        #[doc(hidden)]
+        fn __failwrap_format(
+            request: ::std::option::Option<&::actix_web::HttpRequest>
+        ) -> ::std::option::Option<&'static str> {
+            const FORMATS: &[(&str, &str)] = &[#((#names, #essences)),*];
+
+            let mut accepted = request
+                .and_then(|request| {
+                    <::actix_web::http::header::Accept as ::actix_web::http::header::Header>
+                        ::parse(request)
+                        .ok()
+                })
+                .map(|accept| accept.0)
+                .unwrap_or_default();
+
+            if accepted.is_empty() {
+                return ::std::option::Option::Some(FORMATS[0].0);
+            }
+
+            // media types with a zero quality are explicitly not acceptable.
+            let excluded = accepted
+                .iter()
+                .filter(|item| item.quality == ::actix_web::http::header::Quality::ZERO)
+                .map(|item| item.item.essence_str().to_owned())
+                .collect::<::std::vec::Vec<_>>();
+
+            accepted.retain(|item| item.quality != ::actix_web::http::header::Quality::ZERO);
+
+            for mime in ::actix_web::http::header::Accept(accepted).ranked() {
+                let found = FORMATS
+                    .iter()
+                    .filter(|(_, essence)| !excluded.iter().any(|excluded| excluded == essence))
+                    .find(|(_, essence)| {
+                        mime.type_() == ::actix_web::mime::STAR
+                            || mime.essence_str() == *essence
+                            || (mime.subtype() == ::actix_web::mime::STAR
+                                && essence.split('/').next() == ::std::option::Option::Some(
+                                    mime.type_().as_str()
+                                ))
+                    });
+
+                if let ::std::option::Option::Some((name, _)) = found {
+                    return ::std::option::Option::Some(*name);
+                }
+            }
+
+            #not_acceptable
+        }
+    }
+}
+
+/// **`format_helpers()`**
+///
+/// Generates the escaping functions needed by the
+/// declared response formats.
+pub fn format_helpers(input: &ErrorResponse) -> TokenStream2 {
+    let uses = |names: &[&str]| {
+        input
+            .response_formats()
+            .iter()
+            .any(|format| {
+                names.contains(
+                    &format
+                        .name()
+                        .as_str(),
+                )
+            })
+    };
+
+    let reason_helper = if uses(&["problem_json", "html", "jsonapi"]) {
+        quote! {
+            #[doc(hidden)]
+            fn __failwrap_status_reason(status: u16) -> &'static str {
+                ::actix_web::http::StatusCode::from_u16(status)
+                    .ok()
+                    .and_then(|status| status.canonical_reason())
+                    .unwrap_or_default()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let json_helper = if uses(&["json", "problem_json", "jsonapi"]) {
+        quote! {
+            #[doc(hidden)]
+            fn __failwrap_json_string(value: &str) -> ::std::string::String {
+                let mut escaped = ::std::string::String::with_capacity(value.len() + 2);
+
+                escaped.push('"');
+                for character in value.chars() {
+                    match character {
+                        '"' => escaped.push_str("\\\""),
+                        '\\' => escaped.push_str("\\\\"),
+                        '\n' => escaped.push_str("\\n"),
+                        '\r' => escaped.push_str("\\r"),
+                        '\t' => escaped.push_str("\\t"),
+                        character if character.is_control() => escaped
+                            .push_str(&::std::format!("\\u{:04x}", u32::from(character))),
+                        character => escaped.push(character),
+                    }
+                }
+                escaped.push('"');
+
+                escaped
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let html_helper = if uses(&["html"]) || input.has_html_templates() {
+        quote! {
+            #[doc(hidden)]
+            fn __failwrap_html_escape(value: &str) -> ::std::string::String {
+                let mut escaped = ::std::string::String::with_capacity(value.len());
+
+                for character in value.chars() {
+                    match character {
+                        '&' => escaped.push_str("&amp;"),
+                        '<' => escaped.push_str("&lt;"),
+                        '>' => escaped.push_str("&gt;"),
+                        '"' => escaped.push_str("&quot;"),
+                        '\'' => escaped.push_str("&#39;"),
+                        character => escaped.push(character),
+                    }
+                }
+
+                escaped
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #reason_helper
+        #json_helper
+        #html_helper
+    }
+}
+
+/// **`format_body()`**
+///
+/// Generates the expression that renders the error
+/// response in a specific format. The expression expects
+/// `builder`, `status`, `code` and `message` to be in scope.
+fn format_body(format: &ResponseFormat) -> TokenStream2 {
+    let content_type = format.content_type();
+
+    let body = match format
+        .name()
+        .as_str()
+    {
+        "json" => quote! {
+            ::std::format!(
+                "{{\"status\":{},\"code\":{},\"message\":{}}}",
+                status,
+                Self::__failwrap_json_string(code),
+                Self::__failwrap_json_string(&message)
+            )
+        },
+        "problem_json" => quote! {
+            ::std::format!(
+                "{{\"type\":\"about:blank\",\"title\":{},\"status\":{},\"detail\":{}}}",
+                Self::__failwrap_json_string(Self::__failwrap_status_reason(status)),
+                status,
+                Self::__failwrap_json_string(&message)
+            )
+        },
+        "html" => quote! {
+            self.__failwrap_html_template(status, &message)
+                .unwrap_or_else(|| {
+                    let title = ::std::format!("{} {}", status, Self::__failwrap_status_reason(status));
+
+                    ::std::format!(
+                        ::std::concat!(
+                            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{0}</title>",
+                            "</head><body><h1>{0}</h1><p>{1}</p></body></html>"
+                        ),
+                        title,
+                        Self::__failwrap_html_escape(&message)
+                    )
+                })
+        },
+        "jsonapi" => quote! {
+            ::std::format!(
+                "{{\"errors\":[{{\"status\":\"{}\",\"code\":{},\"title\":{},\"detail\":{}{}}}]}}",
+                status,
+                Self::__failwrap_json_string(code),
+                Self::__failwrap_json_string(Self::__failwrap_status_reason(status)),
+                Self::__failwrap_json_string(&message),
+                self.__failwrap_jsonapi_source()
+                    .map(|source| ::std::format!(",\"source\":{source}"))
+                    .unwrap_or_default()
+            )
+        },
+        "msgpack" => {
+            return encoded_body(content_type, &quote! { ::rmp_serde::to_vec_named(&envelope) });
+        },
+        "cbor" => {
+            return encoded_body(
+                content_type,
+                &quote! {{
+                    let mut buffer = ::std::vec::Vec::new();
+                    ::ciborium::into_writer(&envelope, &mut buffer).map(|()| buffer)
+                }},
+            );
+        },
+        _ => quote! { message },
+    };
+
+    quote! {
+        builder
+            .content_type(#content_type)
+            .body(#body)
+    }
+}
+
+/// **`encoded_body()`**
+///
+/// Generates the expression that renders the structured
+/// error envelope with a binary encoder, the `encoder` expression
+/// expects an `envelope` in scope and returns a `Result` with
+/// the encoded bytes. The response falls back to plain text
+/// if the envelope can't be encoded.
+fn encoded_body(content_type: &str, encoder: &TokenStream2) -> TokenStream2 {
+    quote! {{
+        let encoded = {
+            let envelope = self.__failwrap_envelope(status, code, &message);
+            #encoder
+        };
+
+        match encoded {
+            ::std::result::Result::Ok(body) => builder
+                .content_type(#content_type)
+                .body(body),
+            ::std::result::Result::Err(_) => builder
+                .content_type("text/plain; charset=utf-8")
+                .body(message),
+        }
+    }}
+}
+
+/// **`envelope_fields_arm()`**
+///
+/// Generates the match arm that serializes the `#[expose]`
+/// annotated fields of a variant as a map.
+fn envelope_fields_arm(input: &ErrorResponse, variant: &ErrorResponseVariant) -> TokenStream2 {
+    let enum_name = input.enum_name();
+    let variant_name = &variant
+        .variant()
+        .ident;
+    let exposed_fields = variant.exposed_fields();
+    let (keys, members) = exposed_fields
+        .iter()
+        .map(|member| match member {
+            Member::Named(ident) => (ident.to_string(), member),
+            Member::Unnamed(index) => (
+                index
+                    .index
+                    .to_string(),
+                member,
+            ),
+        })
+        .collect::<(Vec<_>, Vec<_>)>();
+    let bindings = keys
+        .iter()
+        .map(|key| field_binding(key))
+        .collect::<Vec<_>>();
+
+    let pattern = if let VariantFields::Unnamed(fields) = &variant
+        .variant()
+        .fields
+    {
+        let positions = (0..fields
+            .unnamed
+            .len())
+            .map(|idx| {
+                if exposed_fields.contains(&Member::Unnamed(Index::from(idx))) {
+                    let binding = field_binding(&idx.to_string());
+                    quote! { #binding }
+                } else {
+                    quote! { _ }
+                }
+            });
+
+        quote! { #enum_name::#variant_name(#(#positions),*) }
+    } else {
+        quote! { #enum_name::#variant_name { #(#members: #bindings,)* .. } }
+    };
+    let length = keys.len();
+
+    quote! {
+        #pattern => {
+            let mut map = serializer.serialize_map(::std::option::Option::Some(#length))?;
+            #(map.serialize_entry(#keys, #bindings)?;)*
+            map.end()
+        }
+    }
+}
+
+/// **`format_jsonapi_source()`**
+///
+/// Generates a function returning the JSON:API `source`
+/// object for the variants annotated with `#[source_pointer(..)]`
+/// or `#[source_parameter(..)]`. Nothing is generated if the
+/// `jsonapi` format is not declared.
+pub fn format_jsonapi_source(input: &ErrorResponse) -> TokenStream2 {
+    if !input
+        .response_formats()
+        .iter()
+        .any(|format| format.name() == "jsonapi")
+    {
+        return quote! {};
+    }
+
+    let source_arms = input
+        .variants()
+        .iter()
+        .filter_map(|variant| {
+            let members =
+                [("pointer", variant.source_pointer()), ("parameter", variant.source_parameter())]
+                    .into_iter()
+                    .filter_map(|(key, value)| {
+                        value.map(|value| {
+                            quote! {
+                                ::std::format!(
+                                    "\"{}\":{}",
+                                    #key,
+                                    Self::__failwrap_json_string(#value)
+                                )
+                            }
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+            if members.is_empty() {
+                return None;
+            }
+
+            let variant_head = variant_match_head(variant);
+
+            Some(quote! {
+                #variant_head ::std::option::Option::Some(::std::format!(
+                    "{{{}}}",
+                    [#(#members),*].join(",")
+                ))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(unreachable_patterns)]
+        fn __failwrap_jsonapi_source(&self) -> ::std::option::Option<::std::string::String> {
+            match self {
+                #(#source_arms,)*
+                _ => ::std::option::Option::None
+            }
+        }
+    }
+}
+
+/// **`format_html_template()`**
+///
+/// Generates a function rendering the `#[html_template(..)]`
+/// of a variant, falling back to the enum template. The function
+/// returns None for variants without a template. Nothing is
+/// generated if there are no templates and the `html` format
+/// is not declared.
+pub fn format_html_template(input: &ErrorResponse) -> TokenStream2 {
+    let uses_html = input
+        .response_formats()
+        .iter()
+        .any(|format| format.name() == "html");
+
+    if !uses_html && !input.has_html_templates() {
+        return quote! {};
+    }
+
+    let mut files = Vec::new();
+    let template_arms = input
+        .variants()
+        .iter()
+        .filter_map(|variant| {
+            let template = variant
+                .html_template()
+                .or(input.html_template())?;
+
+            if !files.contains(&template.file()) {
+                files.push(template.file());
+            }
+
+            let variant_head = variant_binding_head(variant);
+            let pushes = template
+                .segments()
+                .iter()
+                .map(template_segment);
+
+            Some(quote! {
+                #variant_head {
+                    let mut __page = ::std::string::String::new();
+                    #(#pushes)*
+                    ::std::option::Option::Some(__page)
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(unreachable_patterns)]
+        fn __failwrap_html_template(
+            &self,
+            __status: u16,
+            __message: &str
+        ) -> ::std::option::Option<::std::string::String> {
+            #(const _: &str = ::std::include_str!(#files);)*
+
+            match self {
+                #(#template_arms,)*
+                _ => ::std::option::Option::None
+            }
+        }
+    }
+}
+
+/// **`template_segment()`**
+///
+/// Generates the statement that appends a template
+/// segment to the rendered `__page`, escaping the
+/// placeholder values.
+fn template_segment(segment: &TemplateSegment) -> TokenStream2 {
+    let name = match segment {
+        TemplateSegment::Literal(literal) => return quote! { __page.push_str(#literal); },
+        TemplateSegment::Placeholder(name) => name.as_str(),
+    };
+
+    let value = match name {
+        "message" => quote! { __message },
+        "status" => {
+            return quote! { __page.push_str(&::std::string::ToString::to_string(&__status)); };
+        },
+        _ => {
+            let binding = field_binding(name);
+
+            quote! { &::std::string::ToString::to_string(#binding) }
+        },
+    };
+
+    quote! { __page.push_str(&Self::__failwrap_html_escape(#value)); }
+}
+
+/// **`format_envelope()`**
+///
+/// Generates a function returning the structured error
+/// envelope, a `serde` serializable map containing the status,
+/// code, message and the `#[expose]` annotated fields. Nothing
+/// is generated if no binary format is declared.
+pub fn format_envelope(input: &ErrorResponse) -> TokenStream2 {
+    if !input
+        .response_formats()
+        .iter()
+        .any(|format| {
+            ["msgpack", "cbor"].contains(
+                &format
+                    .name()
+                    .as_str(),
+            )
+        })
+    {
+        return quote! {};
+    }
+
+    let enum_name = input.enum_name();
+
+    let field_arms = input
+        .variants()
+        .iter()
+        .filter(|variant| {
+            !variant
+                .exposed_fields()
+                .is_empty()
+        })
+        .map(|variant| envelope_fields_arm(input, variant))
+        .collect::<Vec<_>>();
+
+    let fallback_arm = if field_arms.len()
+        < input
+            .variants()
+            .len()
+    {
+        quote! {
+            _ => serializer
+                .serialize_map(::std::option::Option::Some(0))?
+                .end()
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #[doc(hidden)]
+        fn __failwrap_envelope<'a>(
+            &'a self,
+            status: u16,
+            code: &'static str,
+            message: &'a str
+        ) -> impl ::serde::Serialize + 'a {
+            struct Fields<'a>(&'a #enum_name);
+
+            impl ::serde::Serialize for Fields<'_> {
+                fn serialize<S: ::serde::Serializer>(
+                    &self,
+                    serializer: S
+                ) -> ::std::result::Result<S::Ok, S::Error> {
+                    use ::serde::ser::SerializeMap as _;
+
+                    match self.0 {
+                        #(#field_arms,)*
+                        #fallback_arm
+                    }
+                }
+            }
+
+            struct Envelope<'a> {
+                status: u16,
+                code: &'static str,
+                message: &'a str,
+                fields: Fields<'a>,
+            }
+
+            impl ::serde::Serialize for Envelope<'_> {
+                fn serialize<S: ::serde::Serializer>(
+                    &self,
+                    serializer: S
+                ) -> ::std::result::Result<S::Ok, S::Error> {
+                    use ::serde::ser::SerializeMap as _;
+
+                    let mut map = serializer.serialize_map(::std::option::Option::Some(4))?;
+                    map.serialize_entry("status", &self.status)?;
+                    map.serialize_entry("code", self.code)?;
+                    map.serialize_entry("message", self.message)?;
+                    map.serialize_entry("fields", &self.fields)?;
+                    map.end()
+                }
+            }
+
+            Envelope {
+                status,
+                code,
+                message,
+                fields: Fields(self),
+            }
+        }
+    }
+}
+
+/// **`formatted_response()`**
+///
+/// Generates the expression that negotiates the format
+/// and renders the error response accordingly.
+pub fn formatted_response(input: &ErrorResponse) -> TokenStream2 {
+    let arms = input
+        .response_formats()
+        .iter()
+        .map(|format| {
+            let name = format.name();
+            let body = format_body(format);
+
+            quote! { ::std::option::Option::Some(#name) => #body }
+        });
+
+    quote! {
+        match Self::__failwrap_format(request) {
+            #(#arms,)*
+            _ => ::actix_web::HttpResponse::NotAcceptable().finish()
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! [`route_registry`] Output Module
+//!
+//! Declares the generation of the route registry and the
+//! submissions made to it by each registered item when the
+//! `auto-register` feature is enabled.
+//!
+//! [`route_registry`]: crate::route_registry
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::Attribute;
+
+use crate::helpers::path_templates::normalize_path_template;
+
+/// **`registry_route()`**
+///
+/// Generates a `(method, path, normalized path)` entry for
+/// the routes declared by a registry submission, gated by
+/// the `cfg` attributes of the route.
+pub fn registry_route<'a>(
+    method: &str,
+    path: &str,
+    cfg_attributes: impl IntoIterator<Item = &'a Attribute>,
+) -> TokenStream2 {
+    let cfg_attributes = cfg_attributes.into_iter();
+    let normalized_path = normalize_path_template(path);
+
+    quote! {
+        #(#cfg_attributes)*
+        (#method, #path, #normalized_path)
+    }
+}
+
+/// **`registry_submission()`**
+///
+/// Generates the submission of an item to the route registry
+/// declared by [`route_registry`], the `register` tokens are the
+/// body of a function receiving `__config`.
+///
+/// [`route_registry`]: crate::route_registry
+pub fn registry_submission<'a>(
+    name: &str,
+    cfg_attributes: impl IntoIterator<Item = &'a Attribute>,
+    routes: &[TokenStream2],
+    register: &TokenStream2,
+) -> TokenStream2 {
+    let cfg_attributes = cfg_attributes.into_iter();
+
+    quote! {
+        #(#cfg_attributes)*
+        const _: () = {
+            #[allow(deprecated)]
+            fn __register(__config: &mut ::actix_web::web::ServiceConfig) {
+                #register
+            }
+
+            ::inventory::submit! {
+                crate::__failwrap_registry::Registration {
+                    name: ::std::concat!(::std::module_path!(), "::", #name),
+                    routes: &[#(#routes),*],
+                    register: __register,
+                }
+            }
+        };
+    }
+}
+
+/// **`route_registry_output()`**
+///
+/// Generates the [`route_registry`] macro output.
+///
+/// [`route_registry`]: crate::route_registry
+pub fn route_registry_output() -> TokenStream2 {
+    if !cfg!(feature = "auto-register") {
+        return quote! {
+            ::std::compile_error!(
+                "route_registry!() requires the auto-register feature of actix_failwrap."
+            );
+        };
+    }
+
+    quote! {
+        #[doc(hidden)]
+        pub mod __failwrap_registry {
+            pub struct Registration {
+                pub name: &'static str,
+                pub routes: &'static [(&'static str, &'static str, &'static str)],
+                pub register: fn(&mut ::actix_web::web::ServiceConfig),
+            }
+
+            ::inventory::collect!(Registration);
+        }
+
+        /// Registers every route, scope and controller submitted to the
+        /// route registry, panics listing the duplicate method and path
+        /// pairs if any.
+        pub fn register_all(__config: &mut ::actix_web::web::ServiceConfig) {
+            let mut __routes = ::std::vec::Vec::<(&str, &str, &str, &str)>::new();
+            let mut __duplicates = ::std::vec::Vec::<::std::string::String>::new();
+
+            for __registration in ::inventory::iter::<crate::__failwrap_registry::Registration> {
+                for &(__method, __path, __normalized) in __registration.routes {
+                    match __routes
+                        .iter()
+                        .find(|(method, _, normalized, _)| {
+                            *method == __method && *normalized == __normalized
+                        })
+                    {
+                        ::std::option::Option::Some((_, __first_path, _, __first)) => __duplicates
+                            .push(::std::format!(
+                                "{__method} {__path} is declared by {__first} as {__first_path} \
+                                 and {}",
+                                __registration.name
+                            )),
+                        ::std::option::Option::None => __routes
+                            .push((__method, __path, __normalized, __registration.name)),
+                    }
+                }
+            }
+
+            ::std::assert!(
+                __duplicates.is_empty(),
+                "Duplicate routes found while registering:\n{}",
+                __duplicates.join("\n")
+            );
+
+            for __registration in ::inventory::iter::<crate::__failwrap_registry::Registration> {
+                (__registration.register)(__config);
+            }
+        }
+    }
+}
@@ -46,3 +46,402 @@ pub fn parse_error_at_least_one_variant() {
     })
     .expect_err("Expected error required at least one variant.");
 }
+
+/// Complete Translations In [`ErrorResponse`]
+///
+/// This tests whether a variant missing a translation
+/// for a language declared in another variant fails.
+#[test]
+pub fn parse_error_missing_translations() {
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[message(lang = "es", "Error.")]
+            #[message(lang = "ca", "Error.")]
+            X,
+
+            #[message(lang = "es", "Error.")]
+            Y
+        }
+    })
+    .expect_err("Expected error missing translation for ca.");
+
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[message(lang = "es", "Error {0}.")]
+            X(i32),
+
+            #[message(lang = "es", "Error {field}.")]
+            Y { field: i32 }
+        }
+    })
+    .expect("Expected success as all the translations are present.");
+}
+
+/// No Duplicated Translations In [`ErrorResponse`]
+///
+/// This tests whether declaring the same language
+/// twice for a variant fails.
+#[test]
+pub fn parse_error_duplicated_translations() {
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[message(lang = "es", "Error.")]
+            #[message(lang = "es", "Otro error.")]
+            X
+        }
+    })
+    .expect_err("Expected error duplicated translation for es.");
+}
+
+/// Invalid Message Syntax In [`ErrorResponse`]
+///
+/// This tests whether malformed `#[message(..)]`
+/// attributes trigger an error.
+#[test]
+pub fn parse_error_invalid_message() {
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[message(language = "es", "Error.")]
+            X
+        }
+    })
+    .expect_err("Expected error invalid lang key.");
+
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[message(lang = "e s", "Error.")]
+            X
+        }
+    })
+    .expect_err("Expected error invalid language tag.");
+
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[message(lang = "es")]
+            X
+        }
+    })
+    .expect_err("Expected error missing message.");
+}
+
+/// Valid Response Formats In [`ErrorResponse`]
+///
+/// This tests whether unknown, repeated or missing
+/// response formats trigger an error.
+#[test]
+pub fn parse_error_response_formats() {
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json, text)]
+        enum Error { X }
+    })
+    .expect("Expected success as the response formats are valid.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(jsno)]
+        enum Error { X }
+    })
+    .expect_err("Expected error unknown response format.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json, json)]
+        enum Error { X }
+    })
+    .expect_err("Expected error repeated response format.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format()]
+        enum Error { X }
+    })
+    .expect_err("Expected error no response formats.");
+}
+
+/// Response Format Conflicts In [`ErrorResponse`]
+///
+/// This tests whether using a response transformer
+/// together with response formats triggers an error.
+#[test]
+pub fn parse_error_response_format_with_transformer() {
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json)]
+        #[transform_response(transformer)]
+        enum Error { X }
+    })
+    .expect_err("Expected error response format and transformer together.");
+}
+
+/// Not Acceptable Policy In [`ErrorResponse`]
+///
+/// This tests whether only the known not acceptable
+/// policies are allowed.
+#[test]
+pub fn parse_error_not_acceptable_policy() {
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json)]
+        #[not_acceptable(reject)]
+        enum Error { X }
+    })
+    .expect("Expected success as the policy is valid.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json)]
+        #[not_acceptable(ignore)]
+        enum Error { X }
+    })
+    .expect_err("Expected error unknown not acceptable policy.");
+}
+
+/// Feature Gated Response Formats In [`ErrorResponse`]
+///
+/// This tests whether binary response formats fail
+/// when their crate feature is not enabled.
+#[test]
+#[cfg(not(feature = "msgpack"))]
+pub fn parse_error_feature_gated_response_format() {
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(msgpack)]
+        enum Error { X }
+    })
+    .expect_err("Expected error msgpack feature not enabled.");
+}
+
+/// Exposed Fields In [`ErrorResponse`]
+///
+/// This tests whether `#[expose]` is only allowed once
+/// per field and without arguments.
+#[test]
+pub fn parse_error_exposed_fields() {
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            X { #[expose] a: i32, b: i32 },
+            Y(i32, #[expose] i32)
+        }
+    })
+    .expect("Expected success as the exposed fields are valid.");
+
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            X { #[expose(a)] a: i32 }
+        }
+    })
+    .expect_err("Expected error arguments in expose.");
+
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            X { #[expose] #[expose] a: i32 }
+        }
+    })
+    .expect_err("Expected error duplicated expose.");
+}
+
+/// JSON:API Sources In [`ErrorResponse`]
+///
+/// This tests whether JSON:API sources require the `jsonapi`
+/// response format and valid JSON pointers.
+#[test]
+pub fn parse_error_jsonapi_sources() {
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(jsonapi)]
+        enum Error {
+            #[source_pointer("/data/attributes/email")]
+            #[source_parameter("page")]
+            X
+        }
+    })
+    .expect("Expected success as the sources are valid.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json)]
+        enum Error {
+            #[source_parameter("page")]
+            X
+        }
+    })
+    .expect_err("Expected error sources without the jsonapi format.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(jsonapi)]
+        enum Error {
+            #[source_pointer("data/attributes/email")]
+            X
+        }
+    })
+    .expect_err("Expected error source pointer is not a JSON pointer.");
+}
+
+/// HTML Templates In [`ErrorResponse`]
+///
+/// This tests whether HTML templates must exist, only
+/// reference fields of the variants using them and can't
+/// be combined with a response transformer or formats
+/// without html.
+#[test]
+pub fn parse_error_html_templates() {
+    parse2::<ErrorResponse>(quote! {
+        #[html_template("tests/templates/error.html")]
+        enum Error {
+            #[html_template("tests/templates/not_found.html")]
+            X { path: String },
+            #[html_template("tests/templates/invalid.html")]
+            Y(i32),
+            Z
+        }
+    })
+    .expect("Expected success as the templates are valid.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[html_template("tests/templates/missing.html")]
+        enum Error { X }
+    })
+    .expect_err("Expected error missing template.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[html_template("tests/templates/not_found.html")]
+        enum Error {
+            X { path: String },
+            Y
+        }
+    })
+    .expect_err("Expected error unknown field in enum template.");
+
+    parse2::<ErrorResponse>(quote! {
+        enum Error {
+            #[html_template("tests/templates/invalid.html")]
+            X(i32, i32),
+            #[html_template("tests/templates/invalid.html")]
+            Y { value: i32 }
+        }
+    })
+    .expect_err("Expected error unknown positional field.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[transform_response(transformer)]
+        #[html_template("tests/templates/error.html")]
+        enum Error { X }
+    })
+    .expect_err("Expected error template with transformer.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[response_format(json)]
+        #[html_template("tests/templates/error.html")]
+        enum Error { X }
+    })
+    .expect_err("Expected error template without the html format.");
+}
+
+/// Extractor Fallback In [`ErrorResponse`]
+///
+/// This tests whether `#[extractor_fallback(..)]` is parsed
+/// as a variant expression and can't be repeated.
+#[test]
+pub fn parse_error_extractor_fallback() {
+    let error = parse2::<ErrorResponse>(quote! {
+        #[extractor_fallback(Malformed(err.to_string()))]
+        enum Error { Malformed(String) }
+    })
+    .expect("Expected success as the fallback is a variant expression.");
+
+    assert!(
+        error
+            .extractor_fallback()
+            .is_some()
+    );
+
+    parse2::<ErrorResponse>(quote! {
+        #[extractor_fallback(X)]
+        #[extractor_fallback(X)]
+        enum Error { X }
+    })
+    .expect_err("Expected error duplicated extractor_fallback attribute.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[extractor_fallback(fn x() {})]
+        enum Error { X }
+    })
+    .expect_err("Expected error the fallback is not an expression.");
+}
+
+/// Extractor Errors In [`ErrorResponse`]
+///
+/// This tests whether `#[extractor_errors(..)]` is parsed
+/// as `Extractor => Variant` pairs without repetitions.
+#[test]
+pub fn parse_error_extractor_errors() {
+    let error = parse2::<ErrorResponse>(quote! {
+        #[extractor_errors(Json => InvalidBody, Query => InvalidQuery(err.to_string()))]
+        enum Error { InvalidBody, InvalidQuery(String) }
+    })
+    .expect("Expected success as the extractor errors are valid.");
+
+    assert_eq!(
+        error
+            .extractor_errors()
+            .iter()
+            .map(|extractor_error| {
+                extractor_error
+                    .extractor()
+                    .to_string()
+            })
+            .collect::<Vec<_>>(),
+        ["Json", "Query"]
+    );
+
+    parse2::<ErrorResponse>(quote! {
+        #[extractor_errors(Json => X, Json => X)]
+        enum Error { X }
+    })
+    .expect_err("Expected error the Json extractor was already declared.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[extractor_errors()]
+        enum Error { X }
+    })
+    .expect_err("Expected error at least one extractor.");
+
+    parse2::<ErrorResponse>(quote! {
+        #[extractor_errors(Json = X)]
+        enum Error { X }
+    })
+    .expect_err("Expected error the pairs are separated by =>.");
+
+    let error = parse2::<ErrorResponse>(quote! {
+        #[extractor_errors(Jsn => X)]
+        enum Error { X }
+    })
+    .expect_err("Expected error Jsn is not a mappable extractor.");
+
+    assert_eq!(
+        error.to_string(),
+        "Jsn is not a mappable extractor, did you mean Json? expected any of Json, Query, Path, \
+         Form, Header, Cookie."
+    );
+}
+
+/// Extractor Variants In [`ErrorResponse`]
+///
+/// This tests whether the variants named by `#[extractor_errors(..)]`
+/// and `#[extractor_fallback(..)]` must exist in the enum, suggesting
+/// the closest variant otherwise.
+#[test]
+pub fn parse_error_extractor_variants() {
+    let error = parse2::<ErrorResponse>(quote! {
+        #[extractor_errors(Json => InvalidBdy)]
+        enum Error { InvalidBody, InvalidQuery(String) }
+    })
+    .expect_err("Expected error InvalidBdy is not a variant.");
+
+    assert_eq!(
+        error.to_string(),
+        "InvalidBdy is not a variant of Error, did you mean InvalidBody?"
+    );
+
+    let error = parse2::<ErrorResponse>(quote! {
+        #[extractor_fallback(InvalidQury(err.to_string()))]
+        enum Error { InvalidBody, InvalidQuery(String) }
+    })
+    .expect_err("Expected error InvalidQury is not a variant.");
+
+    assert_eq!(
+        error.to_string(),
+        "InvalidQury is not a variant of Error, did you mean InvalidQuery?"
+    );
+}
@@ -0,0 +1,28 @@
+//! Format String Helper Tests
+//!
+//! This tests whether the helpers declared in
+//! [`crate::helpers::formatting`] rewrite format
+//! strings as expected.
+
+use crate::helpers::formatting::bind_format_arguments;
+
+/// Format Arguments Bound To Fields
+///
+/// Tests whether positional arguments and the ones named
+/// after a field are renamed to their field bindings while
+/// escaped braces and any other argument are preserved.
+#[test]
+pub fn bind_format_arguments_fields() {
+    let fields = ["id".to_string()];
+
+    assert_eq!(
+        bind_format_arguments("Error {0} and {1:?}.", &[]),
+        "Error {__field_0} and {__field_1:?}."
+    );
+    assert_eq!(
+        bind_format_arguments("Error {id} {other}.", &fields),
+        "Error {__field_id} {other}."
+    );
+    assert_eq!(bind_format_arguments("Escaped {{0}} {0}.", &[]), "Escaped {{0}} {__field_0}.");
+    assert_eq!(bind_format_arguments("Empty {} {:?}.", &fields), "Empty {} {:?}.");
+}
@@ -0,0 +1,36 @@
+//! HTTP Method Tests
+//!
+//! This tests whether the helpers declared in
+//! [`crate::helpers::http_methods`] validate and
+//! normalize the route methods.
+
+use crate::helpers::http_methods::{is_method_token, normalize_method, standard_method};
+
+/// Method Token Validation
+///
+/// Tests whether extension methods are validated
+/// as RFC 7230 tokens.
+#[test]
+pub fn method_token_validation() {
+    for method in ["PROPFIND", "MKCOL", "REPORT", "LOCK", "PURGE", "X-CUSTOM_1"] {
+        assert!(is_method_token(method), "{method} should be a valid token.");
+    }
+
+    for method in ["", "BAD METHOD", "BAD(METHOD)", "BAD@METHOD", "MÉTHOD"] {
+        assert!(!is_method_token(method), "{method} should not be a valid token.");
+    }
+}
+
+/// Method Normalization
+///
+/// Tests whether standard methods are uppercased while
+/// extension methods are kept as declared.
+#[test]
+pub fn method_normalization() {
+    assert_eq!(standard_method("get"), Some("GET"));
+    assert_eq!(standard_method("PROPFIND"), None);
+
+    assert_eq!(normalize_method("patch"), Some("PATCH".into()));
+    assert_eq!(normalize_method("PropFind"), Some("PropFind".into()));
+    assert_eq!(normalize_method("BAD/METHOD"), None);
+}
@@ -7,7 +7,14 @@
 // XXX: Consider checking the error messages.
 
 pub mod error_response;
+pub mod formatting;
+pub mod http_methods;
+pub mod path_templates;
+pub mod proof_controller;
 pub mod proof_route;
+pub mod proof_scope;
+pub mod response_formats;
 pub mod status_code;
 pub mod status_codes;
+pub mod templates;
 pub mod unique_attr;
@@ -0,0 +1,54 @@
+//! Path Template Helper Tests
+//!
+//! This tests whether the helpers declared in
+//! [`crate::helpers::path_templates`] validate
+//! route paths as `actix_web` parses them.
+
+use crate::helpers::path_templates::{normalize_path_template, parse_path_template};
+
+/// Path Template Segments
+///
+/// Tests whether the dynamic segment names are returned
+/// in order, including custom regex and tail segments.
+#[test]
+pub fn path_template_segments() {
+    assert_eq!(parse_path_template(""), Ok(vec![]));
+    assert_eq!(parse_path_template("/users"), Ok(vec![]));
+    assert_eq!(
+        parse_path_template("/users/{id}/posts/{post_id:\\d{2,4}}"),
+        Ok(vec!["id".into(), "post_id".into()])
+    );
+    assert_eq!(parse_path_template("/files/{tail}*"), Ok(vec!["tail".into()]));
+    assert_eq!(parse_path_template("/dav/{path:.*}"), Ok(vec!["path".into()]));
+}
+
+/// Invalid Path Templates
+///
+/// Tests whether malformed paths are rejected.
+#[test]
+pub fn path_template_invalid() {
+    parse_path_template("users").expect_err("Expected error missing leading slash.");
+    parse_path_template("/users/{id").expect_err("Expected error unbalanced opening brace.");
+    parse_path_template("/users/id}").expect_err("Expected error unbalanced closing brace.");
+    parse_path_template("/users/{}").expect_err("Expected error empty segment name.");
+    parse_path_template("/users/{user id}").expect_err("Expected error invalid segment name.");
+    parse_path_template("/{id}/{id}").expect_err("Expected error duplicate segment name.");
+    parse_path_template("/users/{id:[0-9}").expect_err("Expected error invalid regex.");
+    parse_path_template("/files/{tail:.*}*").expect_err("Expected error tail with regex.");
+    parse_path_template("/files/*").expect_err("Expected error unnamed tail.");
+}
+
+/// Normalized Path Templates
+///
+/// Tests whether the segment names are removed from
+/// paths while keeping their custom regex and tails.
+#[test]
+pub fn path_template_normalized() {
+    assert_eq!(normalize_path_template("/users"), "/users");
+    assert_eq!(normalize_path_template("/users/{id}"), "/users/{}");
+    assert_eq!(
+        normalize_path_template("/users/{user_id}/posts/{id:\\d{2,4}}"),
+        "/users/{}/posts/{:\\d{2,4}}"
+    );
+    assert_eq!(normalize_path_template("/files/{tail}*"), "/files/{}*");
+}
@@ -0,0 +1,152 @@
+//! [`proof_controller`] Tests
+//!
+//! This sub-module tests the [`proof_controller`] macro
+//! parsing, this is unitary testing, so if you want to find
+//! integration testing for this macro look for a `tests`
+//! folder in the crate root.
+//!
+//! [`proof_controller`]: crate::proof_controller
+
+use quote::quote;
+use syn::parse2;
+
+use crate::{ProofControllerBody, ProofControllerMeta};
+
+/// Routes Collected In [`proof_controller`]
+///
+/// This tests whether only the `proof_route` annotated
+/// methods are collected as routes and their attributes
+/// removed from the `impl` block.
+///
+/// [`proof_controller`]: crate::proof_controller
+#[test]
+pub fn proof_controller_collects_routes() {
+    let body = parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list(&self) -> Result<HttpResponse, Error> {}
+
+            #[actix_failwrap::proof_route("POST /")]
+            async fn create(#[error_override(Invalid)] body: Json<Body>) -> Result<HttpResponse, Error> {}
+
+            fn helper(&self) {}
+        }
+    })
+    .expect("Expected success as the controller is valid.");
+
+    assert_eq!(
+        body.routes()
+            .len(),
+        2
+    );
+
+    let item_impl = body.item_impl();
+    let item_impl = quote! { #item_impl }.to_string();
+
+    assert!(!item_impl.contains("proof_route"));
+    assert!(!item_impl.contains("error_override"));
+}
+
+/// Invalid Controllers In [`proof_controller`]
+///
+/// This tests whether unsupported receivers, generics,
+/// trait implementations, options and malformed prefixes
+/// trigger an error.
+///
+/// [`proof_controller`]: crate::proof_controller
+#[test]
+pub fn proof_controller_invalid() {
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list(&mut self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error mutable receiver.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list(self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error owned receiver.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list(self: &mut Self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error typed mutable receiver.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list(self: Box<Self>) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error boxed receiver.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list(self: &'a Self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect("Expected success as typed shared receivers are supported.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl<R> Controller<R> {
+            #[proof_route("GET /")]
+            async fn list(&self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error generic impl block.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /")]
+            async fn list<R: Repo>(&self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error generic method.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Trait for Controller {
+            #[proof_route("GET /")]
+            async fn list(&self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error trait impl block.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /", expose_inner = "list_inner")]
+            async fn list(&self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error expose_inner in controller.");
+
+    parse2::<ProofControllerBody>(quote! {
+        impl Controller {
+            #[proof_route("GET /", auto_register = false)]
+            async fn list(&self) -> Result<HttpResponse, Error> {}
+        }
+    })
+    .expect_err("Expected error auto_register in controller method.");
+
+    parse2::<ProofControllerMeta>(quote! { "/users", auto_register = false })
+        .expect("Expected success as auto_register is a valid option.");
+
+    parse2::<ProofControllerMeta>(quote! { "/users", unknown = false })
+        .expect_err("Expected error unknown option.");
+
+    parse2::<ProofControllerMeta>(quote! { "/users/{id" })
+        .expect_err("Expected error unbalanced prefix segment.");
+
+    let meta = parse2::<ProofControllerMeta>(quote! { "/teams/{team}/users" })
+        .expect("Expected success as the prefix is valid.");
+
+    assert_eq!(meta.prefix_segments(), ["team"]);
+}
@@ -7,9 +7,16 @@
 //!
 //! [`proof_route`]: crate::proof_route
 
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::parse2;
+use syn::parse::{ParseStream, Parser};
+use syn::{LitStr, Visibility, parse2};
 
+use crate::helpers::path_templates::parse_path_template;
+use crate::macro_input::proof_route::ErrorOverride;
+#[cfg(feature = "validator")]
+use crate::macro_input::proof_route::ValidationBackend;
+use crate::macro_output::proof_route::path_field_checks;
 use crate::{ProofRouteBody, ProofRouteMeta};
 
 /// No Space In Between Method And Route In Meta
@@ -25,11 +32,21 @@ pub fn proof_route_meta_no_space() {
 /// Invalid HTTP Method In Meta
 ///
 /// This tests whether an invalid HTTP method triggers
-/// an error in the route meta.
+/// an error in the route meta, while extension methods
+/// are accepted if they are valid tokens.
 #[test]
 pub fn proof_route_meta_invalid_http_method() {
-    parse2::<ProofRouteMeta>(quote! { "BADMETHOD /route/test" })
-        .expect_err("Expected error no space between route meta.");
+    parse2::<ProofRouteMeta>(quote! { "BAD(METHOD) /route/test" })
+        .expect_err("Expected error invalid HTTP method.");
+
+    let meta = parse2::<ProofRouteMeta>(quote! { "PROPFIND|get /dav/{path:.*}" })
+        .expect("Expected success as extension methods are valid.");
+
+    assert_eq!(
+        meta.method_paths()
+            .collect::<Vec<_>>(),
+        [("PROPFIND", "/dav/{path:.*}"), ("GET", "/dav/{path:.*}")]
+    );
 }
 
 /// Actix Attributes Not Allowed In Handler
@@ -159,3 +176,654 @@ pub fn proof_route_matching_arg_override() {
     })
     .expect_err("Expected error because expected the error override to be an expr.");
 }
+
+/// Error Override Forms Test
+///
+/// This tests whether `error_override` accepts both the
+/// variant expression and the `with = function` forms.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_error_override_forms() {
+    let body = parse2::<ProofRouteBody>(quote! {
+        async fn x(
+            #[error_override(InvalidBody { reason: err.to_string() })] a: Json<A>,
+            #[error_override(with = map_error)] b: Query<B>,
+            #[error_override(with == other)] c: Form<C>
+        ) -> Result<HttpResponse, Error> {}
+    })
+    .expect("Expected success as both error override forms are valid.");
+
+    let overrides = body
+        .parameters()
+        .iter()
+        .map(|parameter| {
+            parameter
+                .error_override()
+                .map(|error_override| matches!(error_override, ErrorOverride::With(_)))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(overrides, [Some(false), Some(true), Some(false)]);
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[error_override(with = fn foo() {})] a: ()) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error because the function is not an expression.");
+}
+
+/// Error Kind Overrides Test
+///
+/// This tests whether `error_override` accepts `Kind => Variant`
+/// arms and rejects unknown or repeated kinds and arms after
+/// the `_` wildcard.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_error_kind_overrides() {
+    let body = parse2::<ProofRouteBody>(quote! {
+        async fn x(
+            #[error_override(ContentType | Overflow => A, Deserialize => B(err.to_string()))]
+            a: Json<A>,
+            #[error_override(Parse => A, _ => B)] b: Form<B>,
+            #[error_override(Syntax | Eof => A, Data => B, _ => C)] c: Json<C>
+        ) -> Result<HttpResponse, Error> {}
+    })
+    .expect("Expected success as the error kinds are valid.");
+
+    let wildcards = body
+        .parameters()
+        .iter()
+        .map(|parameter| {
+            parameter
+                .error_override()
+                .map(ErrorOverride::has_wildcard)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(wildcards, [Some(false), Some(true), Some(true)]);
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[error_override(Unknown => A)] a: Json<A>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error Unknown is not an error kind.");
+
+    let error = parse2::<ProofRouteBody>(quote! {
+        async fn x(#[error_override(Overflw => A)] a: Json<A>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error Overflw is not an error kind.");
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Overflw is not a valid extractor error kind, did you mean Overflow?")
+    );
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(
+            #[error_override(Overflow => A, ContentType | Overflow => B)] a: Json<A>
+        ) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error the Overflow kind was already declared.");
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[error_override(_ => A, Overflow => B)] a: Json<A>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error arms after the wildcard.");
+}
+
+/// Handler Attributes Forwarded In [`proof_route`]
+///
+/// This tests whether the service attributes and visibility
+/// are taken from the handler while the rest are kept in it.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_forwarded_attributes() {
+    let body = parse2::<ProofRouteBody>(quote! {
+        /// Documented.
+        #[cfg(feature = "x")]
+        #[deprecated]
+        #[allow(unused)]
+        #[inline]
+        pub async fn x() -> Result<HttpResponse, Error> {
+            Ok(HttpResponse::Ok().finish())
+        }
+    })
+    .expect("Expected success as the handler is valid.");
+
+    assert!(matches!(body.vis(), Visibility::Public(_)));
+    assert_eq!(
+        body.attributes()
+            .len(),
+        4
+    );
+    assert_eq!(
+        body.cfg_attributes()
+            .count(),
+        1
+    );
+    assert!(matches!(
+        body.function()
+            .vis,
+        Visibility::Inherited
+    ));
+    assert_eq!(
+        body.function()
+            .attrs
+            .len(),
+        2
+    );
+}
+
+/// Expose Inner Option In Meta
+///
+/// This tests whether the `expose_inner` option is parsed
+/// and invalid or unknown options trigger an error.
+#[test]
+pub fn proof_route_meta_expose_inner() {
+    let meta = parse2::<ProofRouteMeta>(quote! { "GET /x", expose_inner = "get_x_inner" })
+        .expect("Expected success as the option is valid.");
+
+    assert_eq!(
+        meta.expose_inner()
+            .map(ToString::to_string),
+        Some("get_x_inner".into())
+    );
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", expose_inner = "get x" })
+        .expect_err("Expected error invalid function name.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", expose_inner = "a", expose_inner = "b" })
+        .expect_err("Expected error duplicated option.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", unknown = "a" })
+        .expect_err("Expected error unknown option.");
+}
+
+/// Result Aliases With Declared Error In [`proof_route`]
+///
+/// This tests whether any return type is accepted when the
+/// error type is declared with `error = ..`, except the unit type.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_declared_error() {
+    let meta = parse2::<ProofRouteMeta>(quote! { "GET /x", error = crate::ApiError })
+        .expect("Expected success as the option is valid.");
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x() -> ApiResult<HttpResponse> {
+            Ok(HttpResponse::Ok().finish())
+        }
+    })
+    .expect_err("Expected error because a Result alias is returned without error.");
+
+    (|input: ParseStream| ProofRouteBody::parse_with_error(input, meta.error()))
+        .parse2(quote! {
+            async fn x() -> ApiResult<HttpResponse> {
+                Ok(HttpResponse::Ok().finish())
+            }
+        })
+        .expect("Expected success as the error type is declared.");
+
+    (|input: ParseStream| ProofRouteBody::parse_with_error(input, meta.error()))
+        .parse2(quote! {
+            async fn x() {}
+        })
+        .expect_err("Expected error because the unit type is returned.");
+}
+
+/// Generic Handlers In [`proof_route`]
+///
+/// This tests whether handlers declaring type or const
+/// parameters are detected as generic, while lifetimes
+/// alone are not.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_generic_handlers() {
+    for (handler, expected) in [
+        (quote! { async fn x<R: Repo>(repo: Data<R>) -> Result<_, Error> {} }, true),
+        (quote! { async fn x<const N: usize>() -> Result<_, Error> {} }, true),
+        (quote! { async fn x<R>() -> Result<_, Error> where R: Repo {} }, true),
+        (quote! { async fn x<'a>() -> Result<_, Error> {} }, false),
+        (quote! { async fn x() -> Result<_, Error> {} }, false),
+    ] {
+        assert_eq!(
+            parse2::<ProofRouteBody>(handler)
+                .expect("Expected success as the handler is valid.")
+                .is_generic(),
+            expected
+        );
+    }
+}
+
+/// Auto Register Option In Meta
+///
+/// This tests whether the `auto_register` option is parsed
+/// and only accepts boolean literals once.
+#[test]
+pub fn proof_route_meta_auto_register() {
+    let meta = parse2::<ProofRouteMeta>(quote! { "GET /x" })
+        .expect("Expected success as the meta is valid.");
+
+    assert_eq!(meta.auto_register(), None);
+
+    let meta = parse2::<ProofRouteMeta>(quote! { "GET /x", auto_register = false })
+        .expect("Expected success as the option is valid.");
+
+    assert_eq!(meta.auto_register(), Some(false));
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", auto_register = "false" })
+        .expect_err("Expected error non boolean literal.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", auto_register = true, auto_register = false })
+        .expect_err("Expected error duplicated option.");
+}
+
+/// Feature Gated Auto Register Option
+///
+/// This tests whether enabling the `auto_register` option
+/// triggers an error without the `auto-register` feature.
+#[test]
+#[cfg(not(feature = "auto-register"))]
+pub fn proof_route_meta_feature_gated_auto_register() {
+    parse2::<ProofRouteMeta>(quote! { "GET /x", auto_register = true })
+        .expect_err("Expected error the auto-register feature is disabled.");
+}
+
+/// Multiple Route Declarations In Meta
+///
+/// This tests whether several methods and paths are parsed
+/// and duplicated method and path pairs trigger an error.
+#[test]
+pub fn proof_route_meta_multiple_routes() {
+    let meta = parse2::<ProofRouteMeta>(
+        quote! { "GET|head /files/{id}", "GET /download/{id}", error = Error },
+    )
+    .expect("Expected success as the declarations are valid.");
+
+    assert_eq!(
+        meta.method_paths()
+            .collect::<Vec<_>>(),
+        [("GET", "/files/{id}"), ("HEAD", "/files/{id}"), ("GET", "/download/{id}")]
+    );
+
+    parse2::<ProofRouteMeta>(quote! { "GET|BAD@METHOD /x" })
+        .expect_err("Expected error invalid HTTP method.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET| /x" }).expect_err("Expected error empty method.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET|get /x" })
+        .expect_err("Expected error duplicated method.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET|HEAD /x", "POST|HEAD /x" })
+        .expect_err("Expected error duplicated method and path.");
+}
+
+/// Forwarded Route Options In Meta
+///
+/// This tests whether the `name`, `guard` and `wrap` options
+/// are parsed, allowing several guards and middleware.
+#[test]
+pub fn proof_route_meta_route_options() {
+    let meta = parse2::<ProofRouteMeta>(quote! {
+        "GET /users/{id}",
+        name = "user_detail",
+        guard = "is_json",
+        guard = "guards::is_admin",
+        wrap = "Logger::default()"
+    })
+    .expect("Expected success as the options are valid.");
+
+    assert_eq!(
+        meta.name()
+            .map(LitStr::value),
+        Some("user_detail".into())
+    );
+    assert_eq!(
+        meta.guards()
+            .len(),
+        2
+    );
+    assert_eq!(
+        meta.wraps()
+            .len(),
+        1
+    );
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", name = "a", name = "b" })
+        .expect_err("Expected error duplicated name.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", guard = "not a path" })
+        .expect_err("Expected error invalid guard path.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /x", wrap = "Logger::default(" })
+        .expect_err("Expected error invalid middleware expression.");
+}
+
+/// Path Extractors Against Path Segments
+///
+/// This tests whether invalid paths are rejected and `web::Path`
+/// extractors not matching the segments of the route, its prefix
+/// and `scope_prefix` trigger an error, while struct extractors
+/// are checked by the generated destructuring.
+#[test]
+pub fn proof_route_path_extractors() {
+    parse2::<ProofRouteMeta>(quote! { "GET users/{id}" })
+        .expect_err("Expected error missing leading slash.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /users/{id" })
+        .expect_err("Expected error unbalanced braces.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /users/{id:[0-9}" })
+        .expect_err("Expected error invalid segment regex.");
+
+    let validate = |meta: TokenStream2, body: TokenStream2, prefix: &str| {
+        let meta = parse2::<ProofRouteMeta>(meta).expect("Expected success as the meta is valid.");
+        let body = parse2::<ProofRouteBody>(body).expect("Expected success as the body is valid.");
+        let prefix_segments =
+            parse_path_template(prefix).expect("Expected success as the prefix is valid.");
+
+        body.validate_path_extractors(&meta, prefix, &prefix_segments)
+            .map(|()| path_field_checks(&meta, &body, &prefix_segments).to_string())
+    };
+
+    validate(
+        quote! { "GET /users/{id}", "GET /users/{id}/posts/{post}" },
+        quote! { async fn x(path: web::Path<u32>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect_err("Expected error primitive with several segments.");
+
+    validate(
+        quote! { "GET /users" },
+        quote! { async fn x(path: Path<u32>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect_err("Expected error primitive without segments.");
+
+    validate(
+        quote! { "GET /" },
+        quote! { async fn x(path: Path<u32>) -> Result<HttpResponse, Error> {} },
+        "/users/{id}",
+    )
+    .expect("Expected success as the prefix declares the segment.");
+
+    validate(
+        quote! { "GET /users/{id}/posts/{post}" },
+        quote! { async fn x(path: Path<(u32,)>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect("Expected success as the extra segments are ignored.");
+
+    validate(
+        quote! { "GET /posts/{post}" },
+        quote! { async fn x(path: Path<(u32, u32)>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect_err("Expected error tuple with more values than segments.");
+
+    validate(
+        quote! { "GET /posts/{post}", scope_prefix = "/users/{user}" },
+        quote! { async fn x(path: Path<(u32, u32)>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect("Expected success as the scope declares the other segment.");
+
+    let checks = validate(
+        quote! { "GET /users/{id}/posts/{post}" },
+        quote! { async fn x(path: Path<PostPath>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect("Expected success as struct extractors are checked by the compiler.");
+
+    assert!(checks.contains("let PostPath { id : _ , post : _ , .. } = __value ;"));
+
+    let checks = validate(
+        quote! { "GET /posts/{post}", scope_prefix = "/users/{user}" },
+        quote! { async fn x(path: Path<PostPath>) -> Result<HttpResponse, Error> {} },
+        "/teams/{team}",
+    )
+    .expect("Expected success as struct extractors are checked by the compiler.");
+
+    assert!(checks.contains("let PostPath { user : _ , team : _ , post : _ , .. } = __value ;"));
+
+    let checks = validate(
+        quote! { "GET /users/{id}/posts/{post}" },
+        quote! { async fn x<T>(path: Path<T>) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect("Expected success as generic extractors are not checked.");
+
+    assert_eq!(checks, "");
+}
+
+/// Path Sources In [`proof_route`]
+///
+/// This tests whether `#[path]` parameters must name a segment
+/// of every route declaration, its prefix or the declared
+/// `scope_prefix`, suggesting the closest segment otherwise.
+#[test]
+pub fn proof_route_path_sources() {
+    let validate = |meta: TokenStream2, body: TokenStream2, prefix: &str| {
+        let meta = parse2::<ProofRouteMeta>(meta).expect("Expected success as the meta is valid.");
+        let body = parse2::<ProofRouteBody>(body).expect("Expected success as the body is valid.");
+
+        let prefix_segments =
+            parse_path_template(prefix).expect("Expected success as the prefix is valid.");
+
+        body.validate_path_sources(&meta, prefix, &prefix_segments)
+    };
+
+    let error = validate(
+        quote! { "GET /users/{id}" },
+        quote! { async fn x(#[path] idd: u64) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect_err("Expected error idd is not a segment.");
+
+    assert!(
+        error
+            .to_string()
+            .starts_with(
+                "The path \"/users/{id}\" doesn't declare the idd segment. Did you mean id?"
+            )
+    );
+
+    validate(
+        quote! { "GET /users/{id}", "GET /people" },
+        quote! { async fn x(#[path] id: u64) -> Result<HttpResponse, Error> {} },
+        "",
+    )
+    .expect_err("Expected error id is not a segment of /people.");
+
+    validate(
+        quote! { "GET /posts/{post}", scope_prefix = "/users/{user}" },
+        quote! { async fn x(#[path] user: u64, #[path] post: u64) -> Result<HttpResponse, Error> {} },
+        "/teams/{team}",
+    )
+    .expect("Expected success as the segments are declared.");
+
+    validate(
+        quote! { "GET /" },
+        quote! { async fn x(#[path] team: u64) -> Result<HttpResponse, Error> {} },
+        "/teams/{team}",
+    )
+    .expect("Expected success as the prefix declares the segment.");
+
+    parse2::<ProofRouteMeta>(quote! { "GET /", scope_prefix = "users/{id}" })
+        .expect_err("Expected error missing leading slash in scope_prefix.");
+}
+
+/// Parameter Sources In [`proof_route`]
+///
+/// This tests whether the `#[path]`, `#[query]`, `#[header(..)]`
+/// and `#[cookie(..)]` parameter attributes are parsed and
+/// removed from the handler function.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_parameter_sources() {
+    let body = parse2::<ProofRouteBody>(quote! {
+        async fn x(
+            #[path] id: u64,
+            #[query("per_page")] limit: Option<u32>,
+            #[header] x_api_key: String,
+            #[cookie("session")] #[error_override(Unauthorized)] sid: String,
+            body: Json<Body>
+        ) -> Result<HttpResponse, Error> {}
+    })
+    .expect("Expected success as the parameter sources are valid.");
+
+    let sources = body
+        .parameters()
+        .iter()
+        .map(|parameter| {
+            parameter
+                .source()
+                .map(|source| (source.kind(), source.name()))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        sources,
+        [
+            Some(("path", "id")),
+            Some(("query", "per_page")),
+            Some(("header", "x-api-key")),
+            Some(("cookie", "session")),
+            None
+        ]
+    );
+
+    let function = body.function();
+    assert!(
+        !quote! { #function }
+            .to_string()
+            .contains('#')
+    );
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[path] #[query] id: u64) -> Result<HttpResponse, Error> {}
+    })
+    .expect_err("Expected error several sources.");
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[path] (a, b): (u64, u64)) -> Result<HttpResponse, Error> {}
+    })
+    .expect_err("Expected error unnamed pattern without a source name.");
+}
+
+/// Extractor Error Option In [`proof_route`]
+///
+/// This tests whether the `extractor_error = ..` option
+/// is parsed as a variant and can't be repeated.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_meta_extractor_error() {
+    let meta = parse2::<ProofRouteMeta>(quote! { "POST /x", extractor_error = BadRequest })
+        .expect("Expected success as the extractor error is a variant.");
+
+    assert!(matches!(meta.extractor_error(), Some(ErrorOverride::Variant(_))));
+
+    parse2::<ProofRouteMeta>(quote! {
+        "POST /x",
+        extractor_error = BadRequest,
+        extractor_error = Other
+    })
+    .expect_err("Expected error extractor_error was already declared.");
+}
+
+/// Validation Options In [`proof_route`]
+///
+/// This tests whether `#[validate(..)]` parses its backend
+/// and `on_error` options and removes the attribute.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+#[cfg(feature = "validator")]
+pub fn proof_route_validation() {
+    let body = parse2::<ProofRouteBody>(quote! {
+        async fn x(
+            #[validate(backend = validator, on_error = Invalid(fields))] body: Json<Body>
+        ) -> Result<HttpResponse, Error> {}
+    })
+    .expect("Expected success as the validation options are valid.");
+
+    let validation = body.parameters()[0]
+        .validation()
+        .expect("Expected the parameter validation.");
+
+    assert_eq!(validation.backend(), ValidationBackend::Validator);
+    assert!(
+        validation
+            .on_error()
+            .is_some()
+    );
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[validate(backend = other)] body: Json<Body>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error other is not a validation backend.");
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(
+            #[validate(backend = validator, on_error = A, on_error = B)] body: Json<Body>
+        ) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error on_error was already declared.");
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[validate(on_error = A)] body: Json<Body>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error the backend is not declared.");
+}
+
+/// Feature Gated Validation In [`proof_route`]
+///
+/// This tests whether `#[validate(..)]` fails when
+/// no validation feature is enabled.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+#[cfg(not(any(feature = "validator", feature = "garde")))]
+pub fn proof_route_feature_gated_validation() {
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[validate] body: Json<Body>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error the backend is not declared.");
+
+    parse2::<ProofRouteBody>(quote! {
+        async fn x(#[validate(backend = garde)] body: Json<Body>) -> Result<_, Error> {}
+    })
+    .expect_err("Expected error the garde feature is not enabled.");
+}
+
+/// Path To Error Option In [`proof_route`]
+///
+/// This tests whether the `path_to_error = ..` option
+/// requires the `serde_path_to_error` feature to be enabled.
+///
+/// [`proof_route`]: crate::proof_route
+#[test]
+pub fn proof_route_meta_path_to_error() {
+    let meta = parse2::<ProofRouteMeta>(quote! { "POST /x", path_to_error = false })
+        .expect("Expected success as the option is disabled.");
+
+    assert!(!meta.path_to_error());
+
+    let enabled = parse2::<ProofRouteMeta>(quote! { "POST /x", path_to_error = true });
+
+    if cfg!(feature = "serde_path_to_error") {
+        assert!(
+            enabled
+                .expect("Expected success as the feature is enabled.")
+                .path_to_error()
+        );
+    } else {
+        enabled.expect_err("Expected error the serde_path_to_error feature is not enabled.");
+    }
+}
@@ -0,0 +1,217 @@
+//! [`proof_scope`] Tests
+//!
+//! This sub-module tests the [`proof_scope`] macro
+//! parsing, this is unitary testing, so if you want to find
+//! integration testing for this macro look for a `tests`
+//! folder in the crate root.
+//!
+//! [`proof_scope`]: crate::proof_scope
+
+use quote::quote;
+use syn::parse::{ParseStream, Parser};
+use syn::parse2;
+
+use crate::macro_input::proof_scope::ScopeRegistration;
+use crate::{ProofScopeBody, ProofScopeMeta};
+
+/// Scope Registrations In [`proof_scope`]
+///
+/// This tests whether routes, nested scopes and controllers
+/// are collected in order and the scope defaults and prefix
+/// are passed to the nested attributes not declaring them.
+///
+/// [`proof_scope`]: crate::proof_scope
+#[test]
+pub fn proof_scope_registrations() {
+    let meta = parse2::<ProofScopeMeta>(quote! { "/api", error = ApiError })
+        .expect("Expected success as the meta is valid.");
+
+    let body = (|input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta))
+        .parse2(quote! {
+            mod api {
+                #[proof_route("GET /a")]
+                async fn a() -> ApiResult<HttpResponse> {}
+
+                #[proof_route("GET /b", error = OtherError)]
+                async fn b() -> Result<HttpResponse, OtherError> {}
+
+                #[proof_scope("/nested",)]
+                mod nested {}
+
+                #[proof_controller("/controller")]
+                impl Controller {}
+
+                fn helper() {}
+            }
+        })
+        .expect("Expected success as the scope is valid.");
+
+    assert!(matches!(
+        body.registrations(),
+        [
+            ScopeRegistration::Route(..),
+            ScopeRegistration::Route(..),
+            ScopeRegistration::Scope(..),
+            ScopeRegistration::Controller(..)
+        ]
+    ));
+
+    let item_mod = body.item_mod();
+    let item_mod = quote! { #item_mod }.to_string();
+
+    assert!(item_mod.contains(
+        r#"proof_route ("GET /a" , error = ApiError , scope_prefix = "/api" , auto_register = false)"#
+    ));
+    assert!(item_mod.contains(
+        r#"proof_route ("GET /b" , error = OtherError , scope_prefix = "/api" , auto_register = false)"#
+    ));
+    assert!(item_mod.contains(
+        r#"proof_scope ("/nested" , error = ApiError , scope_prefix = "/api" , auto_register = false)"#
+    ));
+    assert!(item_mod.contains(r#"proof_controller ("/controller" , auto_register = false)"#));
+}
+
+/// Scope Extractor Error In [`proof_scope`]
+///
+/// This tests whether the scope `extractor_error` is only
+/// passed to the nested attributes inheriting the scope
+/// error type and not declaring their own.
+///
+/// [`proof_scope`]: crate::proof_scope
+#[test]
+pub fn proof_scope_extractor_error() {
+    let meta =
+        parse2::<ProofScopeMeta>(quote! { "/api", error = ApiError, extractor_error = Invalid })
+            .expect("Expected success as the meta is valid.");
+
+    let body = (|input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta))
+        .parse2(quote! {
+            mod api {
+                #[proof_route("GET /a")]
+                async fn a() -> ApiResult<HttpResponse> {}
+
+                #[proof_route("GET /b", extractor_error = Other)]
+                async fn b() -> ApiResult<HttpResponse> {}
+
+                #[proof_route("GET /c", error = OtherError)]
+                async fn c() -> Result<HttpResponse, OtherError> {}
+
+                #[proof_scope("/nested")]
+                mod nested {}
+            }
+        })
+        .expect("Expected success as the scope is valid.");
+
+    let item_mod = body.item_mod();
+    let item_mod = quote! { #item_mod }.to_string();
+
+    assert!(item_mod.contains(
+        r#"proof_route ("GET /a" , error = ApiError , extractor_error = Invalid , scope_prefix = "/api" , auto_register = false)"#
+    ));
+    assert!(item_mod.contains(
+        r#"proof_route ("GET /b" , extractor_error = Other , error = ApiError , scope_prefix = "/api" , auto_register = false)"#
+    ));
+    assert!(item_mod.contains(
+        r#"proof_route ("GET /c" , error = OtherError , scope_prefix = "/api" , auto_register = false)"#
+    ));
+    assert!(item_mod.contains(
+        r#"proof_scope ("/nested" , error = ApiError , extractor_error = Invalid , scope_prefix = "/api" , auto_register = false)"#
+    ));
+
+    parse2::<ProofScopeMeta>(quote! { "/api", extractor_error = Invalid })
+        .expect_err("Expected error extractor_error without error.");
+}
+
+/// Scope Routes In [`proof_scope`]
+///
+/// This tests whether the routes of the nested scopes and
+/// controllers are collected with their full path and the
+/// `cfg` attributes of every item they are nested in.
+///
+/// [`proof_scope`]: crate::proof_scope
+#[test]
+pub fn proof_scope_routes() {
+    let meta = parse2::<ProofScopeMeta>(quote! { "/api" })
+        .expect("Expected success as the meta is valid.");
+
+    let body = (|input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta))
+        .parse2(quote! {
+            mod api {
+                #[proof_route("get /a")]
+                async fn a() -> Result<HttpResponse, Error> {}
+
+                #[cfg(feature = "x")]
+                #[proof_scope("/nested")]
+                mod nested {
+                    #[proof_route("POST /b")]
+                    async fn b() -> Result<HttpResponse, Error> {}
+                }
+
+                #[proof_controller("/controller")]
+                impl Controller {
+                    #[proof_route("DELETE /{id}")]
+                    async fn c(&self) -> Result<HttpResponse, Error> {}
+                }
+            }
+        })
+        .expect("Expected success as the scope is valid.");
+
+    let routes = body
+        .routes()
+        .iter()
+        .map(|route| {
+            (
+                route.method(),
+                route.path(),
+                route
+                    .cfg_attributes()
+                    .len(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        routes,
+        [("GET", "/api/a", 0), ("POST", "/api/nested/b", 1), ("DELETE", "/api/controller/{id}", 0)]
+    );
+}
+
+/// Invalid Scopes In [`proof_scope`]
+///
+/// This tests whether non inline modules, generic handlers
+/// and unknown options trigger an error.
+///
+/// [`proof_scope`]: crate::proof_scope
+#[test]
+pub fn proof_scope_invalid() {
+    let meta = parse2::<ProofScopeMeta>(quote! { "/api" })
+        .expect("Expected success as the meta is valid.");
+
+    (|input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta))
+        .parse2(quote! { mod api; })
+        .expect_err("Expected error non inline module.");
+
+    (|input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta))
+        .parse2(quote! {
+            mod api {
+                #[proof_route("GET /")]
+                async fn list<R: Repo>(repo: Data<R>) -> Result<HttpResponse, Error> {}
+            }
+        })
+        .expect_err("Expected error generic handler.");
+
+    (|input: ParseStream| ProofScopeBody::parse_with_meta(input, &meta))
+        .parse2(quote! {
+            mod api {
+                #[proof_route("GET /", auto_register = true)]
+                async fn index() -> Result<HttpResponse, Error> {}
+            }
+        })
+        .expect_err("Expected error auto_register declared inside a scope.");
+
+    parse2::<ProofScopeMeta>(quote! { "/api", unknown = X })
+        .expect_err("Expected error unknown option.");
+
+    parse2::<ProofScopeMeta>(quote! { "/api", auto_register = false })
+        .expect("Expected success as auto_register is a valid option.");
+}
@@ -0,0 +1,49 @@
+//! Response Format Literal Tests
+//!
+//! This tests whether the literals declared in
+//! [`crate::helpers::response_formats`] return
+//! the correct results.
+
+use crate::helpers::response_formats::{
+    allowed_formats,
+    closest_format,
+    format_content_type,
+    format_feature,
+};
+
+/// Response Format Content Types
+///
+/// Tests whether every allowed format has a content
+/// type and unknown formats don't.
+#[test]
+pub fn response_format_content_types() {
+    for (format, content_type) in allowed_formats() {
+        assert_eq!(format_content_type(format), Some(*content_type));
+    }
+
+    assert_eq!(format_content_type("json"), Some("application/json"));
+    assert_eq!(format_content_type("yaml"), None);
+}
+
+/// Closest Response Formats
+///
+/// Tests whether the edit distance algorithm returns
+/// the expected corrections for response formats.
+#[test]
+pub fn closest_response_formats() {
+    assert_eq!(closest_format("jsno"), "json");
+    assert_eq!(closest_format("txt"), "text");
+    assert_eq!(closest_format("problem_jsn"), "problem_json");
+    assert_eq!(closest_format("htm"), "html");
+}
+
+/// Feature Gated Response Formats
+///
+/// Tests whether the binary formats report the
+/// feature they require.
+#[test]
+pub fn feature_gated_response_formats() {
+    assert_eq!(format_feature("msgpack"), Some("msgpack"));
+    assert_eq!(format_feature("cbor"), Some("cbor"));
+    assert_eq!(format_feature("json"), None);
+}
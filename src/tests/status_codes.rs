@@ -8,7 +8,12 @@
 
 use std::collections::HashMap;
 
-use crate::helpers::status_codes::{allowed_status_pairs, closest_status, is_status_supported};
+use crate::helpers::status_codes::{
+    allowed_status_pairs,
+    closest_status,
+    is_status_supported,
+    status_to_code,
+};
 
 /// Allowed Status Pairs Getter
 ///
@@ -92,7 +97,7 @@ pub fn allowed_status_pairs_getter() {
 /// are included in the status codes array.
 #[test]
 pub fn supported_status_codes() {
-    const STATUS_TEST: [(&'static str, bool); 62] = [
+    const STATUS_TEST: [(&str, bool); 62] = [
         ("Continue", false),
         ("SwitchingProtocols", false),
         ("Processing", false),
@@ -157,7 +162,7 @@ pub fn supported_status_codes() {
         ("NetworkAuthenticationRequired", true),
     ];
 
-    for (status, allowed) in STATUS_TEST.iter() {
+    for (status, allowed) in &STATUS_TEST {
         assert_eq!(is_status_supported(status), *allowed, "{status} {allowed}");
     }
 }
@@ -168,7 +173,7 @@ pub fn supported_status_codes() {
 /// and returns the expected corrections.
 #[test]
 pub fn closest_status_codes() {
-    static SLIGHT_ERRORS: [(&'static str, &'static str); 39] = [
+    static SLIGHT_ERRORS: [(&str, &str); 39] = [
         ("BadRequet", "BadRequest"),
         ("Unathorized", "Unauthorized"),
         ("PaymntRequired", "PaymentRequired"),
@@ -211,6 +216,19 @@ pub fn closest_status_codes() {
     ];
 
     for (wrong, correct) in SLIGHT_ERRORS {
-        assert_eq!(closest_status(wrong), correct)
+        assert_eq!(closest_status(wrong), correct);
     }
 }
+
+/// Status Identifiers To Codes
+///
+/// Tests whether every allowed status identifier
+/// converts back to its numeric code.
+#[test]
+pub fn status_identifiers_to_codes() {
+    for (code, status) in allowed_status_pairs() {
+        assert_eq!(status_to_code(status), Some(*code));
+    }
+
+    assert_eq!(status_to_code("Ok"), None);
+}
@@ -0,0 +1,38 @@
+//! HTML Template Helper Tests
+//!
+//! This tests whether the helpers declared in
+//! [`crate::helpers::templates`] split templates
+//! as expected.
+
+use crate::helpers::templates::{TemplateSegment, parse_template};
+
+/// Template Segments
+///
+/// Tests whether literal text and placeholders are
+/// split in order, trimming the placeholder names.
+#[test]
+pub fn template_segments() {
+    assert_eq!(
+        parse_template("<h1>{{status}}</h1><p>{{ message }}</p>"),
+        Ok(vec![
+            TemplateSegment::Literal("<h1>".into()),
+            TemplateSegment::Placeholder("status".into()),
+            TemplateSegment::Literal("</h1><p>".into()),
+            TemplateSegment::Placeholder("message".into()),
+            TemplateSegment::Literal("</p>".into()),
+        ])
+    );
+
+    assert_eq!(parse_template("{{0}}"), Ok(vec![TemplateSegment::Placeholder("0".into())]));
+}
+
+/// Invalid Template Placeholders
+///
+/// Tests whether unclosed or malformed placeholders
+/// are rejected.
+#[test]
+pub fn template_invalid_placeholders() {
+    parse_template("<p>{{message</p>").expect_err("Expected error unclosed placeholder.");
+    parse_template("<p>{{}}</p>").expect_err("Expected error empty placeholder.");
+    parse_template("<p>{{a b}}</p>").expect_err("Expected error invalid placeholder.");
+}
@@ -0,0 +1,112 @@
+//! Auto Register Tests
+//!
+//! This tests whether handlers, controllers and scopes declaring
+//! `auto_register = true` submit themselves to the route registry
+//! with the `auto-register` feature, and `register_all` registers
+//! every one of them.
+
+use actix_failwrap::{ErrorResponse, proof_controller, proof_route, route_registry};
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use thiserror::Error;
+
+mod common;
+
+route_registry!();
+
+#[derive(ErrorResponse, Error, Debug)]
+pub enum ApiError {
+    #[error("The resource was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+}
+
+pub struct StatusController;
+
+#[proof_route("GET /health", auto_register = true)]
+async fn health() -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().body("healthy"))
+}
+
+#[proof_route("GET /manual")]
+async fn manual() -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().body("manual"))
+}
+
+#[cfg(any())]
+#[proof_route("GET /health", auto_register = true)]
+async fn disabled() -> Result<HttpResponse, ApiError> {
+    compile_error!("This route must not be compiled.");
+}
+
+#[proof_controller("/status", auto_register = true)]
+impl StatusController {
+    #[proof_route("GET /")]
+    async fn status(&self) -> Result<HttpResponse, ApiError> {
+        Ok(HttpResponse::Ok().body("running"))
+    }
+}
+
+mod api {
+    use actix_failwrap::proof_scope;
+
+    #[proof_scope("/api", error = crate::ApiError, auto_register = true)]
+    pub mod v1 {
+        use actix_failwrap::{proof_route, proof_scope};
+        use actix_web::HttpResponse;
+
+        use crate::ApiError;
+
+        #[proof_route("GET /health")]
+        async fn health() -> Result<HttpResponse, ApiError> {
+            Ok(HttpResponse::Ok().body("api healthy"))
+        }
+
+        #[proof_scope("/users")]
+        mod users {
+            use actix_failwrap::proof_route;
+            use actix_web::HttpResponse;
+
+            use crate::ApiError;
+
+            #[proof_route("GET /missing")]
+            async fn missing_user() -> Result<HttpResponse, ApiError> {
+                Err(ApiError::NotFound)
+            }
+        }
+    }
+}
+
+async fn request(path: &str) -> (u16, String) {
+    call_app(
+        App::new()
+            .app_data(Data::new(StatusController))
+            .configure(register_all),
+        TestRequest::get().uri(path),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_registered_routes() {
+    assert_eq!(request("/health").await, (200, "healthy".into()));
+    assert_eq!(request("/status/").await, (200, "running".into()));
+}
+
+#[actix_web::test]
+async fn test_registered_scopes() {
+    assert_eq!(request("/api/health").await, (200, "api healthy".into()));
+    assert_eq!(request("/api/users/missing").await, (404, "The resource was not found.".into()));
+}
+
+#[actix_web::test]
+async fn test_opted_out_routes() {
+    assert_eq!(
+        request("/manual")
+            .await
+            .0,
+        404
+    );
+}
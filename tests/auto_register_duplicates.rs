@@ -0,0 +1,74 @@
+//! Auto Register Duplicates Tests
+//!
+//! This tests whether `register_all` reports the method and
+//! path pairs declared more than once, including the ones
+//! declared inside scopes and the ones only differing in
+//! their segment names.
+
+use std::panic::catch_unwind;
+
+use actix_failwrap::{ErrorResponse, proof_route, proof_scope, route_registry};
+use actix_web::{App, HttpResponse};
+use thiserror::Error;
+
+route_registry!();
+
+#[derive(ErrorResponse, Error, Debug)]
+pub enum ApiError {
+    #[error("Something went wrong.")]
+    Internal,
+}
+
+#[proof_route("GET /api/users", auto_register = true)]
+async fn list_users() -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /api/users", auto_register = true)]
+async fn create_user() -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Created().finish())
+}
+
+#[proof_route("GET /api/users/{id}", auto_register = true)]
+async fn get_user() -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_scope("/api", auto_register = true)]
+mod api {
+    use actix_failwrap::proof_route;
+    use actix_web::HttpResponse;
+
+    use crate::ApiError;
+
+    #[proof_route("GET /users")]
+    async fn users() -> Result<HttpResponse, ApiError> {
+        Err(ApiError::Internal)
+    }
+
+    #[proof_route("GET /users/{user_id}")]
+    async fn user() -> Result<HttpResponse, ApiError> {
+        Err(ApiError::Internal)
+    }
+}
+
+#[test]
+fn test_duplicate_routes() {
+    let panic = catch_unwind(|| {
+        App::new().configure(register_all);
+    })
+    .expect_err("Expected a panic as routes are declared more than once.");
+    let message = panic
+        .downcast_ref::<String>()
+        .expect("Expected a formatted panic message.");
+
+    // the registration order is unspecified.
+    assert!(message.contains("GET /api/users is declared by"));
+    assert!(message.contains("GET /api/users/{"));
+    assert_eq!(
+        message
+            .matches(" is declared by ")
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,161 @@
+//! Binary Format Tests
+//!
+//! This tests whether the `msgpack` and `cbor` response
+//! formats encode the structured error envelope, including
+//! the `#[expose]` annotated fields, so it can be decoded
+//! back by a client.
+//!
+//! These tests require the `msgpack` and `cbor` features.
+#![cfg(all(feature = "msgpack", feature = "cbor"))]
+
+use std::collections::BTreeMap;
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::Path;
+use actix_web::{App, HttpResponse};
+use common::call_app_parts;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+#[response_format(msgpack, cbor, text)]
+enum TestError {
+    #[error("User {id} not found.")]
+    #[status_code(NotFound)]
+    UserNotFound {
+        #[expose]
+        id: i32,
+        secret: String,
+    },
+
+    #[error("Tuple error containing {0}.")]
+    Tuple(String, #[expose] i32),
+
+    #[error("Page {serializer} of {map}.")]
+    #[status_code(BadRequest)]
+    Shadowing {
+        #[expose]
+        serializer: i32,
+        #[expose]
+        map: i32,
+    },
+
+    #[error("Unit error.")]
+    Unit,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Envelope {
+    status: u16,
+    code: String,
+    message: String,
+    fields: BTreeMap<String, i32>,
+}
+
+#[proof_route("GET /{error_type}")]
+async fn binary_formats(error_type: Path<String>) -> Result<HttpResponse, TestError> {
+    match error_type.as_str() {
+        "named" => Err(TestError::UserNotFound { id: 7, secret: "hidden".into() }),
+        "tuple" => Err(TestError::Tuple("hidden".into(), 69)),
+        "shadowing" => Err(TestError::Shadowing { serializer: 2, map: 3 }),
+        "unit" => Err(TestError::Unit),
+        _ => unreachable!("The test shouldn't even receive any other value."),
+    }
+}
+
+async fn request_envelope(path: &str, accept: &str) -> (String, Envelope) {
+    let (_, headers, body) = call_app_parts(
+        App::new().service(binary_formats),
+        TestRequest::get()
+            .uri(path)
+            .insert_header(("accept", accept)),
+    )
+    .await;
+
+    let content_type = headers
+        .get("content-type")
+        .and_then(|value| {
+            value
+                .to_str()
+                .ok()
+        })
+        .unwrap_or_default()
+        .to_string();
+
+    let envelope = match accept {
+        "application/msgpack" => {
+            rmp_serde::from_slice(&body).expect("Expected a msgpack envelope.")
+        },
+        "application/cbor" => {
+            ciborium::from_reader(body.as_ref()).expect("Expected a cbor envelope.")
+        },
+        _ => unreachable!("The test shouldn't request any other format."),
+    };
+
+    (content_type, envelope)
+}
+
+#[actix_web::test]
+async fn test_binary_format_msgpack_round_trip() {
+    let (content_type, envelope) = request_envelope("/named", "application/msgpack").await;
+
+    assert_eq!(content_type, "application/msgpack");
+    assert_eq!(
+        envelope,
+        Envelope {
+            status: 404,
+            code: "UserNotFound".into(),
+            message: "User 7 not found.".into(),
+            fields: BTreeMap::from([("id".into(), 7)]),
+        }
+    );
+}
+
+#[actix_web::test]
+async fn test_binary_format_cbor_round_trip() {
+    let (content_type, envelope) = request_envelope("/tuple", "application/cbor").await;
+
+    assert_eq!(content_type, "application/cbor");
+    assert_eq!(
+        envelope,
+        Envelope {
+            status: 500,
+            code: "Tuple".into(),
+            message: "Tuple error containing hidden.".into(),
+            fields: BTreeMap::from([("1".into(), 69)]),
+        }
+    );
+}
+
+#[actix_web::test]
+async fn test_binary_format_no_exposed_fields() {
+    let (_, envelope) = request_envelope("/unit", "application/msgpack").await;
+
+    assert_eq!(
+        envelope,
+        Envelope {
+            status: 500,
+            code: "Unit".into(),
+            message: "Unit error.".into(),
+            fields: BTreeMap::new(),
+        }
+    );
+}
+
+#[actix_web::test]
+async fn test_binary_format_local_names() {
+    let (_, envelope) = request_envelope("/shadowing", "application/cbor").await;
+
+    assert_eq!(
+        envelope,
+        Envelope {
+            status: 400,
+            code: "Shadowing".into(),
+            message: "Page 2 of 3.".into(),
+            fields: BTreeMap::from([("serializer".into(), 2), ("map".into(), 3)]),
+        }
+    );
+}
@@ -4,6 +4,13 @@
 //! and can be extended in the case other tests require
 //! more common implementations.
 
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceFactory, ServiceRequest, ServiceResponse};
+use actix_web::http::header::HeaderMap;
+use actix_web::test::{TestRequest, call_service, init_service, read_body};
+use actix_web::web::Bytes;
+use actix_web::{App, Error};
+
 // NOTE: this macro can be used to generate a test for a specific endpoint.
 #[allow(unused_macros)]
 macro_rules! test_http_endpoint {
@@ -11,12 +18,12 @@ macro_rules! test_http_endpoint {
         test $endpoint:ident as $test_name:ident
         with request {
             head: $req_method:ident /$($($req_path_segment:ident)/+)? $(?$($req_query_key:ident=$req_query_value:literal)&*)?;
-            $(headers: { $($req_header_key:ident: $req_header_value:literal)* })?
+            $(headers: { $($req_header_key:tt: $req_header_value:literal)* })?
             $(body: { $req_body:expr })?
         }
         and expect response {
             head: $res_code:literal;
-            $(headers: { $($res_header_key:ident: $res_header_value:literal)* })?
+            $(headers: { $($res_header_key:tt: $res_header_value:literal)* })?
             $(body: { $res_body:expr })?
         }
     ) => {
@@ -66,10 +73,12 @@ macro_rules! test_http_endpoint {
                 .$req_method(url)
                 .headers(
                     ::reqwest::header::HeaderMap::from_iter([
-                        $((
-                            ::reqwest::header::HeaderName::from_static(::std::stringify!($req_header_key)),
+                        $($((
+                            ::reqwest::header::HeaderName::from_static(
+                                test_http_endpoint!(@header_key $req_header_key)
+                            ),
                             ::reqwest::header::HeaderValue::from_static($req_header_value)
-                        )),*
+                        )),*)?
                     ])
                 )
                 $(
@@ -85,7 +94,7 @@ macro_rules! test_http_endpoint {
                 #[allow(unused)]
                 let res_headers = response.headers();
                 $(::std::assert_eq!(
-                    res_headers.get(::std::stringify!($res_header_key)),
+                    res_headers.get(test_http_endpoint!(@header_key $res_header_key)),
                     Some(&::reqwest::header::HeaderValue::from_static($res_header_value))
                 );)*
             })?
@@ -100,11 +109,67 @@ macro_rules! test_http_endpoint {
             )?
 
             server_thread.abort();
-
-            ()
         }
     };
+
+    // NOTE: header keys can be either identifiers or lowercase string literals.
+    (@header_key $key:ident) => {
+        ::std::stringify!($key)
+    };
+    (@header_key $key:literal) => {
+        $key
+    };
 }
 
 #[allow(unused)]
 pub(crate) use test_http_endpoint;
+
+// NOTE: this function can be used to call an app with several services or app
+// data.
+#[allow(unused)]
+pub(crate) async fn call_app_parts<T, B>(
+    app: App<T>,
+    request: TestRequest,
+) -> (u16, HeaderMap, Bytes)
+where
+    T: ServiceFactory<
+            ServiceRequest,
+            Config = (),
+            Response = ServiceResponse<B>,
+            Error = Error,
+            InitError = (),
+        > + 'static,
+    T::Future: 'static,
+    B: MessageBody,
+{
+    let app = init_service(app).await;
+    let response = call_service(&app, request.to_request()).await;
+    let status = response
+        .status()
+        .as_u16();
+    let headers = response
+        .headers()
+        .clone();
+
+    (status, headers, read_body(response).await)
+}
+
+// NOTE: the same as `call_app_parts` for the tests only checking the status and
+// text body.
+#[allow(unused)]
+pub(crate) async fn call_app<T, B>(app: App<T>, request: TestRequest) -> (u16, String)
+where
+    T: ServiceFactory<
+            ServiceRequest,
+            Config = (),
+            Response = ServiceResponse<B>,
+            Error = Error,
+            InitError = (),
+        > + 'static,
+    T::Future: 'static,
+    B: MessageBody,
+{
+    let (status, _, body) = call_app_parts(app, request).await;
+
+    (status, String::from_utf8_lossy(&body).into_owned())
+}
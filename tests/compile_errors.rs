@@ -0,0 +1,11 @@
+//! Compile Errors Tests
+//!
+//! This tests whether misuses of the macros that can only
+//! be detected once expanded, such as overrides naming a
+//! variant the handler error doesn't have, are reported at
+//! the attribute with the closest existing name.
+
+#[test]
+fn test_compile_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,181 @@
+//! Content Negotiation Tests
+//!
+//! This tests whether the `#[response_format(..)]` attribute
+//! renders the error body in the format negotiated from the
+//! `Accept` header, and whether `#[not_acceptable(reject)]`
+//! responds with a 406 when no format matches.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use common::test_http_endpoint;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+#[response_format(json, text, problem_json, html)]
+enum TestError {
+    #[error("The \"user\" <was> not found.")]
+    #[status_code(NotFound)]
+    UserNotFound,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[response_format(text)]
+#[not_acceptable(reject)]
+enum RejectingError {
+    #[error("Rejecting error.")]
+    #[status_code(BadRequest)]
+    Rejecting,
+}
+
+#[proof_route("GET /")]
+async fn content_negotiation() -> Result<HttpResponse, TestError> {
+    Err(TestError::UserNotFound)
+}
+
+#[proof_route("GET /")]
+async fn not_acceptable() -> Result<HttpResponse, RejectingError> {
+    Err(RejectingError::Rejecting)
+}
+
+test_http_endpoint!(
+    test content_negotiation as test_content_negotiation_default
+    with request {
+        head: get /;
+        headers: {
+            "accept": "*/*"
+        }
+    }
+    and expect response {
+        head: 404;
+        headers: {
+            "content-type": "application/json"
+        }
+        body: {
+            r#"{"status":404,"code":"UserNotFound","message":"The \"user\" <was> not found."}"#
+        }
+    }
+);
+
+test_http_endpoint!(
+    test content_negotiation as test_content_negotiation_text
+    with request {
+        head: get /;
+        headers: {
+            "accept": "application/xml, text/*;q=0.5"
+        }
+    }
+    and expect response {
+        head: 404;
+        headers: {
+            "content-type": "text/plain; charset=utf-8"
+        }
+        body: {
+            "The \"user\" <was> not found."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test content_negotiation as test_content_negotiation_problem_json
+    with request {
+        head: get /;
+        headers: {
+            "accept": "application/problem+json"
+        }
+    }
+    and expect response {
+        head: 404;
+        headers: {
+            "content-type": "application/problem+json"
+        }
+        body: {
+            r#"{"type":"about:blank","title":"Not Found","status":404,"detail":"The \"user\" <was> not found."}"#
+        }
+    }
+);
+
+test_http_endpoint!(
+    test content_negotiation as test_content_negotiation_html
+    with request {
+        head: get /;
+        headers: {
+            "accept": "text/html,application/xhtml+xml,*/*;q=0.8"
+        }
+    }
+    and expect response {
+        head: 404;
+        headers: {
+            "content-type": "text/html; charset=utf-8"
+        }
+        body: {
+            concat!(
+                "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>404 Not Found</title>",
+                "</head><body><h1>404 Not Found</h1>",
+                "<p>The &quot;user&quot; &lt;was&gt; not found.</p></body></html>"
+            )
+        }
+    }
+);
+
+test_http_endpoint!(
+    test not_acceptable as test_content_negotiation_reject
+    with request {
+        head: get /;
+        headers: {
+            "accept": "image/png"
+        }
+    }
+    and expect response {
+        head: 406;
+    }
+);
+
+test_http_endpoint!(
+    test not_acceptable as test_content_negotiation_accepted
+    with request {
+        head: get /;
+        headers: {
+            "accept": "text/plain"
+        }
+    }
+    and expect response {
+        head: 400;
+        body: {
+            "Rejecting error."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test content_negotiation as test_content_negotiation_zero_quality
+    with request {
+        head: get /;
+        headers: {
+            "accept": "application/json;q=0, */*;q=0.5"
+        }
+    }
+    and expect response {
+        head: 404;
+        headers: {
+            "content-type": "text/plain; charset=utf-8"
+        }
+        body: {
+            "The \"user\" <was> not found."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test not_acceptable as test_content_negotiation_reject_zero_quality
+    with request {
+        head: get /;
+        headers: {
+            "accept": "text/plain;q=0"
+        }
+    }
+    and expect response {
+        head: 406;
+    }
+);
@@ -0,0 +1,185 @@
+//! Controller Tests
+//!
+//! This tests whether `proof_controller` registers the
+//! `proof_route` methods of an `impl` block under its prefix,
+//! resolving `&self` from the app `web::Data` and naming the
+//! resources after the controller.
+
+use actix_failwrap::{ErrorResponse, proof_controller};
+use actix_web::body::to_bytes;
+use actix_web::test::TestRequest;
+use actix_web::web::{Data, Json, Path};
+use actix_web::{App, HttpRequest, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum UserError {
+    #[error("The user was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+
+    #[error("Invalid user.")]
+    #[status_code(BadRequest)]
+    InvalidUser,
+}
+
+#[derive(Deserialize)]
+struct NewUser {
+    name: String,
+}
+
+struct UsersController {
+    names: Vec<String>,
+}
+
+#[proof_controller("/users")]
+impl UsersController {
+    #[proof_route("GET /{id}")]
+    async fn get(&self, id: Path<usize>) -> Result<HttpResponse, UserError> {
+        self.find(id.into_inner())
+            .map(|name| HttpResponse::Ok().body(name.to_string()))
+    }
+
+    #[proof_route("GET /{id}/initial")]
+    #[allow(clippy::needless_arbitrary_self_type)]
+    async fn initial(self: &Self, id: Path<usize>) -> Result<HttpResponse, UserError> {
+        self.find(id.into_inner())
+            .map(|name| HttpResponse::Ok().body(name[..1].to_string()))
+    }
+
+    #[proof_route("POST /")]
+    async fn create(
+        #[error_override(InvalidUser)] user: Json<NewUser>,
+    ) -> Result<HttpResponse, UserError> {
+        Ok(HttpResponse::Created().body(
+            user.into_inner()
+                .name,
+        ))
+    }
+
+    fn find(&self, id: usize) -> Result<&str, UserError> {
+        self.names
+            .get(id)
+            .map(String::as_str)
+            .ok_or(UserError::NotFound)
+    }
+}
+
+struct TeamsController;
+
+#[proof_controller("/teams")]
+impl TeamsController {
+    #[proof_route("GET /{id}")]
+    async fn get(id: Path<usize>) -> Result<HttpResponse, UserError> {
+        Ok(HttpResponse::Ok().body(format!("team {id}")))
+    }
+
+    #[proof_route("GET /links/{id}")]
+    async fn links(req: HttpRequest, id: Path<usize>) -> Result<HttpResponse, UserError> {
+        let link = |name: &str| {
+            req.url_for(name, [id.to_string()])
+                .map(|url| {
+                    url.path()
+                        .to_string()
+                })
+                .unwrap_or_default()
+        };
+
+        Ok(HttpResponse::Ok().body(format!(
+            "{} {}",
+            link("UsersController::get"),
+            link("TeamsController::get")
+        )))
+    }
+}
+
+async fn request(request: TestRequest) -> (u16, String) {
+    call_app(
+        App::new()
+            .app_data(Data::new(UsersController { names: vec!["Ferris".into()] }))
+            .configure(UsersController::configure)
+            .configure(TeamsController::configure),
+        request,
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_controller_self_route() {
+    assert_eq!(request(TestRequest::get().uri("/users/0")).await, (200, "Ferris".into()));
+    assert_eq!(
+        request(TestRequest::get().uri("/users/1")).await,
+        (404, "The user was not found.".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_controller_typed_receiver_route() {
+    assert_eq!(request(TestRequest::get().uri("/users/0/initial")).await, (200, "F".into()));
+}
+
+#[actix_web::test]
+async fn test_controller_associated_route() {
+    assert_eq!(
+        request(
+            TestRequest::post()
+                .uri("/users/")
+                .set_json(serde_json::json!({ "name": "Corro" }))
+        )
+        .await,
+        (201, "Corro".into())
+    );
+
+    assert_eq!(
+        request(
+            TestRequest::post()
+                .uri("/users/")
+                .set_payload("{}")
+        )
+        .await,
+        (400, "Invalid user.".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_controller_resource_names() {
+    assert_eq!(
+        request(TestRequest::get().uri("/teams/links/3")).await,
+        (200, "/users/3 /teams/3".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_controller_method_direct_call() {
+    let controller = UsersController { names: vec!["Ferris".into()] };
+
+    let found = controller
+        .get(Path::from(0))
+        .await
+        .expect("Expected the user to exist.");
+
+    assert_eq!(found.status(), 200);
+    assert_eq!(
+        to_bytes(found.into_body())
+            .await
+            .expect("Expected a readable body."),
+        "Ferris"
+    );
+
+    assert!(matches!(
+        controller
+            .get(Path::from(1))
+            .await,
+        Err(UserError::NotFound)
+    ));
+
+    let created = UsersController::create(Json(NewUser { name: "Corro".into() }))
+        .await
+        .expect("Expected the user to be created.");
+
+    assert_eq!(created.status(), 201);
+}
@@ -0,0 +1,169 @@
+//! Error Kind Overrides Tests
+//!
+//! This tests whether `#[error_override(Kind => Variant, ..)]`
+//! renders each kind of `Json`, `Form` and `Query` error as
+//! its own variant, telling broken JSON apart from JSON
+//! with the wrong shape, and leaving the unmatched kinds to
+//! the `_` arm or the enum `#[extractor_fallback(..)]`.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::{Form, Json, JsonConfig, Query};
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestQuery {
+    token: String,
+}
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestPerson {
+    name: String,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[extractor_fallback(Malformed)]
+enum TestError {
+    #[error("Wrong media type.")]
+    #[status_code(UnsupportedMediaType)]
+    WrongMediaType,
+
+    #[error("Too large.")]
+    #[status_code(PayloadTooLarge)]
+    TooLarge,
+
+    #[error("Invalid JSON.")]
+    #[status_code(BadRequest)]
+    InvalidJson,
+
+    #[error("Unprocessable: {0}")]
+    #[status_code(UnprocessableEntity)]
+    Unprocessable(String),
+
+    #[error("Invalid query.")]
+    #[status_code(BadRequest)]
+    InvalidQuery,
+
+    #[error("Invalid form.")]
+    #[status_code(BadRequest)]
+    InvalidForm,
+
+    #[error("Malformed request.")]
+    #[status_code(BadRequest)]
+    Malformed,
+}
+
+#[proof_route("POST /people")]
+async fn create_person(
+    #[error_override(Deserialize => InvalidQuery)] _query: Query<TestQuery>,
+    #[error_override(
+        ContentType => WrongMediaType,
+        Overflow => TooLarge,
+        Syntax | Eof => InvalidJson,
+        Deserialize => Unprocessable(err.to_string()),
+        _ => Malformed,
+    )]
+    _body: Json<TestPerson>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /forms")]
+async fn create_form(
+    #[error_override(ContentType | Parse => InvalidForm)] _form: Form<TestPerson>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn request(uri: &str, content_type: &str, body: &'static str) -> (u16, String) {
+    call_app(
+        App::new()
+            .app_data(JsonConfig::default().limit(32))
+            .service(create_person)
+            .service(create_form),
+        TestRequest::post()
+            .uri(uri)
+            .insert_header(("Content-Type", content_type))
+            .set_payload(body),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_error_kind_overrides_json() {
+    const JSON: &str = "application/json";
+
+    assert_eq!(
+        request("/people?token=abc", "text/plain", r#"{"name":"John"}"#).await,
+        (415, "Wrong media type.".to_owned())
+    );
+    assert_eq!(
+        request("/people?token=abc", JSON, r#"{"name":"John Jacob Jingleheimer Schmidt"}"#)
+            .await
+            .0,
+        413
+    );
+
+    let (status, body) = request("/people?token=abc", JSON, r#"{"age":50}"#).await;
+
+    assert_eq!(status, 422);
+    assert!(body.starts_with("Unprocessable: Json deserialize error: missing field"));
+    assert_eq!(
+        request("/people?token=abc", JSON, r#"{"name":"Jo"#).await,
+        (400, "Invalid JSON.".to_owned())
+    );
+    assert_eq!(
+        request("/people?token=abc", JSON, r#"{"name" "John"}"#).await,
+        (400, "Invalid JSON.".to_owned())
+    );
+    assert_eq!(
+        request("/people?token=abc", JSON, r#"{"name":"John"}"#)
+            .await
+            .0,
+        200
+    );
+}
+
+#[actix_web::test]
+async fn test_error_kind_overrides_query() {
+    assert_eq!(
+        request("/people", "application/json", r#"{"name":"John"}"#).await,
+        (400, "Invalid query.".to_owned())
+    );
+}
+
+#[actix_web::test]
+async fn test_error_kind_overrides_unmatched() {
+    const FORM: &str = "application/x-www-form-urlencoded";
+
+    assert_eq!(
+        request("/forms", "application/json", "name=John").await,
+        (400, "Invalid form.".to_owned())
+    );
+    assert_eq!(request("/forms", FORM, "age=50").await, (400, "Invalid form.".to_owned()));
+    assert_eq!(
+        request("/forms", FORM, "name=John")
+            .await
+            .0,
+        200
+    );
+
+    assert_eq!(
+        call_app(
+            App::new().service(create_form),
+            TestRequest::post()
+                .uri("/forms")
+                .insert_header(("Content-Type", FORM))
+                .set_payload(format!("name={}", "a".repeat(32 * 1024))),
+        )
+        .await,
+        (400, "Malformed request.".into())
+    );
+}
@@ -0,0 +1,117 @@
+//! Error Override Bindings Tests
+//!
+//! This tests whether `#[error_override(..)]` variants can
+//! use the original extractor error and the request through
+//! the `err` and `req` bindings, and whether the function
+//! form `#[error_override(with = ..)]` maps them instead.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::{Json, Query};
+use actix_web::{App, Error, HttpRequest, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestPerson {
+    name: String,
+    age: i32,
+}
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestQuery {
+    token: String,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[default_status_code(BadRequest)]
+enum TestError {
+    #[error("Invalid body at {path}: {reason}")]
+    InvalidBody { path: String, reason: String },
+
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    #[error("Unknown item, {0}")]
+    #[status_code(NotFound)]
+    UnknownItem(String),
+}
+
+fn map_query_error(err: Error, req: &HttpRequest) -> TestError {
+    TestError::InvalidQuery(format!("{} ({})", err.as_response_error(), req.path()))
+}
+
+#[proof_route("POST /people")]
+async fn create_person(
+    #[error_override(InvalidBody { path: req.path().to_owned(), reason: err.to_string() })]
+    _body: Json<TestPerson>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("GET /search")]
+async fn search(
+    #[error_override(with = map_query_error)] _query: Query<TestQuery>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("GET /items/{id}")]
+async fn get_item(
+    #[path]
+    #[error_override(UnknownItem(err.to_string()))]
+    id: u64,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(id.to_string()))
+}
+
+async fn request(request: TestRequest) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(create_person)
+            .service(search)
+            .service(get_item),
+        request,
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_error_override_variant_bindings() {
+    let (status, body) = request(
+        TestRequest::post()
+            .uri("/people")
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"name":"John"}"#),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+    assert!(body.starts_with("Invalid body at /people: Json deserialize error: missing field"));
+}
+
+#[actix_web::test]
+async fn test_error_override_with_function() {
+    let (status, body) = request(TestRequest::get().uri("/search")).await;
+
+    assert_eq!(status, 400);
+    assert!(body.starts_with("Invalid query: Query deserialize error: missing field"));
+    assert!(body.ends_with("(/search)"));
+
+    let (status, _) = request(TestRequest::get().uri("/search?token=abc")).await;
+
+    assert_eq!(status, 200);
+}
+
+#[actix_web::test]
+async fn test_error_override_parameter_source_bindings() {
+    let (status, body) = request(TestRequest::get().uri("/items/abc")).await;
+
+    assert_eq!(status, 404);
+    assert_eq!(body, "Unknown item, Invalid path id.");
+}
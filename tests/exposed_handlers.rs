@@ -0,0 +1,70 @@
+//! Exposed Handler Tests
+//!
+//! This tests whether `expose_inner = ".."` exposes the
+//! original handler function so it can be called directly
+//! with constructed extractors, while the route keeps working.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use actix_web::body::to_bytes;
+use actix_web::web::Query;
+use common::test_http_endpoint;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("The name can't be empty.")]
+    #[status_code(BadRequest)]
+    EmptyName,
+}
+
+#[derive(Deserialize)]
+struct Greeting {
+    name: String,
+}
+
+#[proof_route("GET /", expose_inner = "greet_inner")]
+async fn greet(greeting: Query<Greeting>) -> Result<HttpResponse, TestError> {
+    if greeting
+        .name
+        .is_empty()
+    {
+        return Err(TestError::EmptyName);
+    }
+
+    Ok(HttpResponse::Ok().body(format!("Hello {}!", greeting.name)))
+}
+
+#[actix_web::test]
+async fn test_exposed_handler_direct_call() {
+    let response = greet_inner(Query(Greeting { name: "Ferris".into() }))
+        .await
+        .expect("Expected success as the name is not empty.");
+    let body = to_bytes(response.into_body())
+        .await
+        .expect("Expected the body to be readable.");
+
+    assert_eq!(body, "Hello Ferris!");
+
+    let error = greet_inner(Query(Greeting { name: String::new() }))
+        .await
+        .expect_err("Expected error as the name is empty.");
+
+    assert_eq!(Into::<HttpResponse>::into(error).status(), 400);
+}
+
+test_http_endpoint!(
+    test greet as test_exposed_handler_route
+    with request {
+        head: get /?name="Ferris";
+    }
+    and expect response {
+        head: 200;
+        body: {
+            "Hello Ferris!"
+        }
+    }
+);
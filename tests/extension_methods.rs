@@ -0,0 +1,66 @@
+//! Extension Methods Tests
+//!
+//! This tests whether handlers declaring extension
+//! methods such as WebDAV methods are routed only
+//! for requests with those methods.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::http::Method;
+use actix_web::test::TestRequest;
+use actix_web::web::Path;
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum DavError {
+    #[error("Something went wrong.")]
+    Internal,
+}
+
+#[proof_route("PROPFIND|MKCOL /dav/{path:.*}")]
+async fn dav(path: Path<String>) -> Result<HttpResponse, DavError> {
+    Ok(HttpResponse::Ok().body(path.into_inner()))
+}
+
+#[proof_route("PURGE /cache")]
+async fn purge() -> Result<HttpResponse, DavError> {
+    Err(DavError::Internal)
+}
+
+async fn request(method: &[u8], path: &str) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(dav)
+            .service(purge),
+        TestRequest::default()
+            .method(Method::from_bytes(method).expect("Expected a valid method."))
+            .uri(path),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_extension_methods() {
+    assert_eq!(request(b"PROPFIND", "/dav/files/a.txt").await, (200, "files/a.txt".into()));
+    assert_eq!(request(b"MKCOL", "/dav/files").await, (200, "files".into()));
+    assert_eq!(request(b"PURGE", "/cache").await, (500, "Something went wrong.".into()));
+}
+
+#[actix_web::test]
+async fn test_other_methods_not_routed() {
+    assert_eq!(
+        request(b"GET", "/dav/files")
+            .await
+            .0,
+        404
+    );
+    assert_eq!(
+        request(b"purge", "/cache")
+            .await
+            .0,
+        404
+    );
+}
@@ -0,0 +1,137 @@
+//! Extractor Errors Tests
+//!
+//! This tests whether the `#[extractor_errors(..)]` table
+//! of the handler error enum renders each extractor error
+//! as the mapped variant, falling back to the enum
+//! `#[extractor_fallback(..)]` for the rest.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::{self, Json, Query};
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestQuery {
+    token: String,
+}
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestPerson {
+    name: String,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[default_status_code(BadRequest)]
+#[extractor_errors(
+    Json => InvalidBody,
+    Query => InvalidQuery(req.query_string().to_owned()),
+    Path => NotFound,
+)]
+#[extractor_fallback(Malformed)]
+enum TestError {
+    #[error("Invalid body.")]
+    InvalidBody,
+
+    #[error("Invalid query \"{0}\".")]
+    InvalidQuery(String),
+
+    #[error("Not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+
+    #[error("Malformed request.")]
+    Malformed,
+
+    #[error("Invalid form.")]
+    InvalidForm,
+}
+
+#[proof_route("POST /people/{id}")]
+async fn create_person(
+    _query: Query<TestQuery>,
+    #[path] id: u64,
+    _body: web::Json<TestPerson>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(id.to_string()))
+}
+
+#[proof_route("POST /forms")]
+async fn create_form(
+    #[error_override(InvalidForm)] _body: Json<TestPerson>,
+    _form: web::Form<TestQuery>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+type PersonBody = Json<TestPerson>;
+
+#[proof_route("POST /aliases")]
+async fn create_alias(_body: PersonBody) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /generics")]
+async fn create_generic<T: for<'de> Deserialize<'de> + 'static>(
+    _body: Json<T>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn request(uri: &str, body: &'static str) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(create_person)
+            .service(create_form)
+            .service(create_alias)
+            .service(create_generic::<TestPerson>()),
+        TestRequest::post()
+            .uri(uri)
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload(body),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_extractor_errors_table() {
+    assert_eq!(
+        request("/people/1?other=1", r#"{"name":"John"}"#).await,
+        (400, "Invalid query \"other=1\".".to_owned())
+    );
+    assert_eq!(
+        request("/people/abc?token=abc", r#"{"name":"John"}"#).await,
+        (404, "Not found.".to_owned())
+    );
+    assert_eq!(
+        request("/people/1?token=abc", r#"{"age":50}"#).await,
+        (400, "Invalid body.".to_owned())
+    );
+    assert_eq!(
+        request("/people/1?token=abc", r#"{"name":"John"}"#)
+            .await
+            .0,
+        200
+    );
+}
+
+#[actix_web::test]
+async fn test_extractor_errors_precedence() {
+    assert_eq!(request("/forms", r#"{"age":50}"#).await, (400, "Invalid form.".to_owned()));
+    assert_eq!(
+        request("/forms", r#"{"name":"John"}"#).await,
+        (400, "Malformed request.".to_owned())
+    );
+}
+
+#[actix_web::test]
+async fn test_extractor_errors_type_resolution() {
+    assert_eq!(request("/aliases", r#"{"age":50}"#).await, (400, "Invalid body.".to_owned()));
+    assert_eq!(request("/generics", r#"{"age":50}"#).await, (400, "Invalid body.".to_owned()));
+}
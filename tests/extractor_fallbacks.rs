@@ -0,0 +1,131 @@
+//! Extractor Fallback Tests
+//!
+//! This tests whether extractor errors without an override are
+//! rendered through the handler error enum, preferring the
+//! parameter `#[error_override(..)]`, then the route
+//! `extractor_error` and then the enum `#[extractor_fallback(..)]`.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::{Json, Query};
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestQuery {
+    token: String,
+}
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct TestPerson {
+    name: String,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[response_format(json)]
+#[default_status_code(BadRequest)]
+#[extractor_fallback(Malformed(err.to_string()))]
+enum FallbackError {
+    #[error("Malformed request: {0}")]
+    Malformed(String),
+
+    #[error("Invalid route input.")]
+    InvalidRoute,
+
+    #[error("Invalid query.")]
+    #[status_code(UnprocessableEntity)]
+    InvalidQuery,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+enum PlainError {
+    #[error("Invalid route input.")]
+    #[status_code(BadRequest)]
+    InvalidRoute,
+}
+
+#[proof_route("POST /enum")]
+async fn enum_fallback(
+    _query: Query<TestQuery>,
+    _body: Json<TestPerson>,
+) -> Result<HttpResponse, FallbackError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /route", extractor_error = InvalidRoute)]
+async fn route_fallback(
+    #[error_override(InvalidQuery)] _query: Query<TestQuery>,
+    _body: Json<TestPerson>,
+) -> Result<HttpResponse, FallbackError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /plain/route", extractor_error = InvalidRoute)]
+async fn plain_route_fallback(_body: Json<TestPerson>) -> Result<HttpResponse, PlainError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /plain")]
+async fn plain(_body: Json<TestPerson>) -> Result<HttpResponse, PlainError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn request(uri: &str) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(enum_fallback)
+            .service(route_fallback)
+            .service(plain_route_fallback)
+            .service(plain),
+        TestRequest::post()
+            .uri(uri)
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"age":50}"#),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_extractor_fallback_enum() {
+    let (status, body) = request("/enum").await;
+
+    assert_eq!(status, 400);
+    assert!(body.starts_with(
+        r#"{"status":400,"code":"Malformed","message":"Malformed request: Query deserialize error"#
+    ));
+}
+
+#[actix_web::test]
+async fn test_extractor_fallback_precedence() {
+    let (status, body) = request("/route").await;
+
+    assert_eq!(status, 422);
+    assert_eq!(body, r#"{"status":422,"code":"InvalidQuery","message":"Invalid query."}"#);
+
+    let (status, body) = request("/route?token=abc").await;
+
+    assert_eq!(status, 400);
+    assert_eq!(body, r#"{"status":400,"code":"InvalidRoute","message":"Invalid route input."}"#);
+}
+
+#[actix_web::test]
+async fn test_extractor_fallback_route_without_enum_fallback() {
+    let (status, body) = request("/plain/route").await;
+
+    assert_eq!(status, 400);
+    assert_eq!(body, "Invalid route input.");
+}
+
+#[actix_web::test]
+async fn test_extractor_fallback_absent() {
+    let (status, body) = request("/plain").await;
+
+    assert_eq!(status, 400);
+    assert!(body.starts_with("Json deserialize error"));
+}
@@ -0,0 +1,89 @@
+//! Generic Handler Tests
+//!
+//! This tests whether generic `proof_route` handlers generate
+//! a service factory function that can be registered for
+//! concrete type arguments.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::dev::HttpServiceFactory;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("The repository is empty.")]
+    #[status_code(NotFound)]
+    Empty,
+}
+
+trait Repo: 'static {
+    fn list(&self) -> Vec<&'static str>;
+}
+
+struct MemoryRepo;
+
+impl Repo for MemoryRepo {
+    fn list(&self) -> Vec<&'static str> {
+        vec!["ferris", "corro"]
+    }
+}
+
+struct EmptyRepo;
+
+impl Repo for EmptyRepo {
+    fn list(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+}
+
+#[proof_route("GET /")]
+async fn list<R>(repo: Data<R>) -> Result<HttpResponse, TestError>
+where
+    R: Repo,
+{
+    let items = repo.list();
+
+    if items.is_empty() {
+        return Err(TestError::Empty);
+    }
+
+    Ok(HttpResponse::Ok().body(items.join(",")))
+}
+
+#[proof_route("GET /")]
+async fn count<const N: usize>() -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(N.to_string()))
+}
+
+async fn request_body<R: Repo>(
+    repo: R,
+    service: impl HttpServiceFactory + 'static,
+) -> (u16, String) {
+    call_app(
+        App::new()
+            .app_data(Data::new(repo))
+            .service(service),
+        TestRequest::get().uri("/"),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_generic_handler_type_argument() {
+    assert_eq!(request_body(MemoryRepo, list::<MemoryRepo>()).await, (200, "ferris,corro".into()));
+
+    assert_eq!(
+        request_body(EmptyRepo, list::<EmptyRepo>()).await,
+        (404, "The repository is empty.".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_generic_handler_const_argument() {
+    assert_eq!(request_body(EmptyRepo, count::<3>()).await, (200, "3".into()));
+}
@@ -0,0 +1,88 @@
+//! Handler Attribute Tests
+//!
+//! This tests whether the service generated by `proof_route`
+//! inherits the handler visibility, doc comments and
+//! `cfg`, `deprecated` and lint attributes.
+
+#![allow(deprecated)]
+
+use common::test_http_endpoint;
+use routes::{deprecated_route, documented_route, linted_route};
+
+mod common;
+
+mod routes {
+    use actix_failwrap::{ErrorResponse, proof_route};
+    use actix_web::HttpResponse;
+    use thiserror::Error;
+
+    #[derive(ErrorResponse, Error, Debug)]
+    pub enum TestError {
+        #[error("Unreachable.")]
+        Unreachable,
+    }
+
+    /// This route is documented.
+    #[proof_route("GET /")]
+    pub async fn documented_route() -> Result<HttpResponse, TestError> {
+        Ok(HttpResponse::Ok().body("documented"))
+    }
+
+    #[deprecated = "Use documented_route instead."]
+    #[proof_route("GET /")]
+    pub(crate) async fn deprecated_route() -> Result<HttpResponse, TestError> {
+        Ok(HttpResponse::Ok().body("deprecated"))
+    }
+
+    #[proof_route("GET /")]
+    #[cfg(any())]
+    pub async fn disabled_route() -> Result<HttpResponse, TestError> {
+        compile_error!("This route must not be compiled.");
+    }
+
+    #[allow(unreachable_code)]
+    #[proof_route("GET /")]
+    pub async fn linted_route() -> Result<HttpResponse, TestError> {
+        return Err(TestError::Unreachable);
+        Ok(HttpResponse::Ok().finish())
+    }
+}
+
+test_http_endpoint!(
+    test documented_route as test_public_route
+    with request {
+        head: get /;
+    }
+    and expect response {
+        head: 200;
+        body: {
+            "documented"
+        }
+    }
+);
+
+test_http_endpoint!(
+    test deprecated_route as test_deprecated_route
+    with request {
+        head: get /;
+    }
+    and expect response {
+        head: 200;
+        body: {
+            "deprecated"
+        }
+    }
+);
+
+test_http_endpoint!(
+    test linted_route as test_linted_route
+    with request {
+        head: get /;
+    }
+    and expect response {
+        head: 500;
+        body: {
+            "Unreachable."
+        }
+    }
+);
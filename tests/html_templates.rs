@@ -0,0 +1,127 @@
+//! HTML Template Tests
+//!
+//! This tests whether the `#[html_template(..)]` attribute
+//! renders the error pages from the enum and variant
+//! templates, escaping the placeholder values.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use actix_web::web::Path;
+use common::test_http_endpoint;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+#[html_template("tests/templates/error.html")]
+enum TestError {
+    #[error("Page {path} not found.")]
+    #[status_code(NotFound)]
+    #[html_template("tests/templates/not_found.html")]
+    NotFound { path: String },
+
+    #[error("Something <failed> & broke.")]
+    Internal,
+
+    #[error("Out of range.")]
+    #[status_code(BadRequest)]
+    #[html_template("tests/templates/out_of_range.html")]
+    OutOfRange { page: u32, pages: u32 },
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[response_format(json, html)]
+enum NegotiatedError {
+    #[error("Invalid value {0}.")]
+    #[status_code(BadRequest)]
+    #[html_template("tests/templates/invalid.html")]
+    Invalid(i32),
+}
+
+#[proof_route("GET /{error_type}")]
+async fn html_templates(error_type: Path<String>) -> Result<HttpResponse, TestError> {
+    match error_type.as_str() {
+        "variant" => Err(TestError::NotFound { path: "/<admin>".into() }),
+        "enum" => Err(TestError::Internal),
+        "range" => Err(TestError::OutOfRange { page: 9, pages: 3 }),
+        _ => unreachable!("The test shouldn't even receive any other value."),
+    }
+}
+
+#[proof_route("GET /")]
+async fn negotiated_templates() -> Result<HttpResponse, NegotiatedError> {
+    Err(NegotiatedError::Invalid(7))
+}
+
+test_http_endpoint!(
+    test html_templates as test_html_template_variant
+    with request {
+        head: get /variant;
+    }
+    and expect response {
+        head: 404;
+        headers: {
+            "content-type": "text/html; charset=utf-8"
+        }
+        body: {
+            concat!(
+                "<!DOCTYPE html>\n<html><body><h1>404 Not Found</h1>",
+                "<p>Page /&lt;admin&gt; not found.</p><code>/&lt;admin&gt;</code></body></html>\n"
+            )
+        }
+    }
+);
+
+test_http_endpoint!(
+    test html_templates as test_html_template_enum
+    with request {
+        head: get /enum;
+    }
+    and expect response {
+        head: 500;
+        headers: {
+            "content-type": "text/html; charset=utf-8"
+        }
+        body: {
+            concat!(
+                "<!DOCTYPE html>\n<html><body><h1>500</h1>",
+                "<p>Something &lt;failed&gt; &amp; broke.</p></body></html>\n"
+            )
+        }
+    }
+);
+
+test_http_endpoint!(
+    test html_templates as test_html_template_local_names
+    with request {
+        head: get /range;
+    }
+    and expect response {
+        head: 400;
+        headers: {
+            "content-type": "text/html; charset=utf-8"
+        }
+        body: {
+            "<p>Page 9 of 3: Out of range. (400).</p>\n"
+        }
+    }
+);
+
+test_http_endpoint!(
+    test negotiated_templates as test_html_template_negotiated
+    with request {
+        head: get /;
+        headers: {
+            "accept": "text/html"
+        }
+    }
+    and expect response {
+        head: 400;
+        headers: {
+            "content-type": "text/html; charset=utf-8"
+        }
+        body: {
+            "<p>Invalid value 7. Received 7 (400).</p>\n"
+        }
+    }
+);
@@ -0,0 +1,97 @@
+//! JSON:API Error Tests
+//!
+//! This tests whether the `jsonapi` response format renders
+//! JSON:API error objects, including the `source` member from
+//! the `#[source_pointer(..)]` and `#[source_parameter(..)]`
+//! variant attributes.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use actix_web::web::Path;
+use common::test_http_endpoint;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+#[response_format(jsonapi)]
+enum TestError {
+    #[error("The email is already in use.")]
+    #[status_code(UnprocessableEntity)]
+    #[source_pointer("/data/attributes/email")]
+    EmailInUse,
+
+    #[error("The page must be a number.")]
+    #[status_code(BadRequest)]
+    #[source_parameter("page")]
+    InvalidPage,
+
+    #[error("The resource was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+}
+
+#[proof_route("GET /{error_type}")]
+async fn jsonapi_errors(error_type: Path<String>) -> Result<HttpResponse, TestError> {
+    match error_type.as_str() {
+        "pointer" => Err(TestError::EmailInUse),
+        "parameter" => Err(TestError::InvalidPage),
+        "none" => Err(TestError::NotFound),
+        _ => unreachable!("The test shouldn't even receive any other value."),
+    }
+}
+
+test_http_endpoint!(
+    test jsonapi_errors as test_jsonapi_source_pointer
+    with request {
+        head: get /pointer;
+        headers: {
+            "accept": "application/vnd.api+json"
+        }
+    }
+    and expect response {
+        head: 422;
+        headers: {
+            "content-type": "application/vnd.api+json"
+        }
+        body: {
+            concat!(
+                r#"{"errors":[{"status":"422","code":"EmailInUse","title":"Unprocessable Entity","#,
+                r#""detail":"The email is already in use.","#,
+                r#""source":{"pointer":"/data/attributes/email"}}]}"#
+            )
+        }
+    }
+);
+
+test_http_endpoint!(
+    test jsonapi_errors as test_jsonapi_source_parameter
+    with request {
+        head: get /parameter;
+    }
+    and expect response {
+        head: 400;
+        body: {
+            concat!(
+                r#"{"errors":[{"status":"400","code":"InvalidPage","title":"Bad Request","#,
+                r#""detail":"The page must be a number.","source":{"parameter":"page"}}]}"#
+            )
+        }
+    }
+);
+
+test_http_endpoint!(
+    test jsonapi_errors as test_jsonapi_no_source
+    with request {
+        head: get /none;
+    }
+    and expect response {
+        head: 404;
+        body: {
+            concat!(
+                r#"{"errors":[{"status":"404","code":"NotFound","title":"Not Found","#,
+                r#""detail":"The resource was not found."}]}"#
+            )
+        }
+    }
+);
@@ -0,0 +1,144 @@
+//! Localized Message Tests
+//!
+//! This tests whether the `#[message(lang = "..", "..")]`
+//! attribute is negotiated from the `Accept-Language` header,
+//! falling back to the `Display` implementation when no
+//! translation matches or it has a zero quality. An exact
+//! tag is preferred over its primary language.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use actix_web::web::Path;
+use common::test_http_endpoint;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("User {id} not found.")]
+    #[message(lang = "es", "Usuario {id} no encontrado.")]
+    #[message(lang = "es-MX", "No se encontró el usuario {id}.")]
+    #[message(lang = "ca", "Usuari {id} no trobat.")]
+    #[status_code(NotFound)]
+    UserNotFound { id: i32 },
+
+    #[error("Tuple error containing {0}.")]
+    #[message(lang = "es", "Error de tupla que contiene {0}.")]
+    #[message(lang = "es-MX", "Error de tupla con {0}.")]
+    #[message(lang = "ca", "Error de tupla que conté {0}.")]
+    Tuple(i32),
+
+    #[error("Invalid request {request}.")]
+    #[message(lang = "es", "Solicitud {request} no válida.")]
+    #[message(lang = "es-MX", "La solicitud {request} no es válida.")]
+    #[message(lang = "ca", "Sol·licitud {request} no vàlida.")]
+    #[status_code(BadRequest)]
+    InvalidRequest { request: String },
+}
+
+#[proof_route("GET /{error_type}")]
+async fn localized_messages(error_type: Path<String>) -> Result<HttpResponse, TestError> {
+    match error_type.as_str() {
+        "named" => Err(TestError::UserNotFound { id: 7 }),
+        "tuple" => Err(TestError::Tuple(69)),
+        "request" => Err(TestError::InvalidRequest { request: "#1".into() }),
+        _ => unreachable!("The test shouldn't even receive any other value."),
+    }
+}
+
+test_http_endpoint!(
+    test localized_messages as test_localized_message_primary_language
+    with request {
+        head: get /named;
+        headers: {
+            "accept-language": "es-ES,en;q=0.5"
+        }
+    }
+    and expect response {
+        head: 404;
+        body: {
+            "Usuario 7 no encontrado."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test localized_messages as test_localized_message_exact_tag
+    with request {
+        head: get /named;
+        headers: {
+            "accept-language": "es-MX"
+        }
+    }
+    and expect response {
+        head: 404;
+        body: {
+            "No se encontró el usuario 7."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test localized_messages as test_localized_message_quality
+    with request {
+        head: get /tuple;
+        headers: {
+            "accept-language": "es;q=0.4, ca;q=0.8"
+        }
+    }
+    and expect response {
+        head: 500;
+        body: {
+            "Error de tupla que conté 69."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test localized_messages as test_localized_message_fallback
+    with request {
+        head: get /named;
+        headers: {
+            "accept-language": "fr"
+        }
+    }
+    and expect response {
+        head: 404;
+        body: {
+            "User 7 not found."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test localized_messages as test_localized_message_zero_quality
+    with request {
+        head: get /named;
+        headers: {
+            "accept-language": "es;q=0, fr"
+        }
+    }
+    and expect response {
+        head: 404;
+        body: {
+            "User 7 not found."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test localized_messages as test_localized_message_request_field
+    with request {
+        head: get /request;
+        headers: {
+            "accept-language": "es"
+        }
+    }
+    and expect response {
+        head: 400;
+        body: {
+            "Solicitud #1 no válida."
+        }
+    }
+);
@@ -0,0 +1,86 @@
+//! Multiple Routes Tests
+//!
+//! This tests whether handlers declaring several methods
+//! and paths are routed for each of them, and only them.
+
+use actix_failwrap::{ErrorResponse, proof_controller, proof_route};
+use actix_web::http::Method;
+use actix_web::test::TestRequest;
+use actix_web::web::Path;
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum FileError {
+    #[error("The file was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+}
+
+struct UsersController;
+
+#[proof_route("GET|HEAD /files/{id}", "GET /download/{id}")]
+async fn get_file(id: Path<String>) -> Result<HttpResponse, FileError> {
+    match id.as_str() {
+        "a" => Ok(HttpResponse::Ok().body("file a")),
+        _ => Err(FileError::NotFound),
+    }
+}
+
+#[proof_controller("/users")]
+impl UsersController {
+    #[proof_route("GET /me", "GET /current")]
+    async fn me() -> Result<HttpResponse, FileError> {
+        Ok(HttpResponse::Ok().body("ferris"))
+    }
+}
+
+async fn request(method: Method, path: &str) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(get_file)
+            .configure(UsersController::configure),
+        TestRequest::default()
+            .method(method)
+            .uri(path),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_multiple_methods() {
+    assert_eq!(request(Method::GET, "/files/a").await, (200, "file a".into()));
+    assert_eq!(
+        request(Method::HEAD, "/files/a")
+            .await
+            .0,
+        200
+    );
+    assert_eq!(
+        request(Method::POST, "/files/a")
+            .await
+            .0,
+        404
+    );
+}
+
+#[actix_web::test]
+async fn test_multiple_paths() {
+    assert_eq!(request(Method::GET, "/download/a").await, (200, "file a".into()));
+    assert_eq!(request(Method::GET, "/download/b").await, (404, "The file was not found.".into()));
+    assert_eq!(
+        request(Method::HEAD, "/download/a")
+            .await
+            .0,
+        404
+    );
+}
+
+#[actix_web::test]
+async fn test_controller_multiple_paths() {
+    assert_eq!(request(Method::GET, "/users/me").await, (200, "ferris".into()));
+    assert_eq!(request(Method::GET, "/users/current").await, (200, "ferris".into()));
+}
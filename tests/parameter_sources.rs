@@ -0,0 +1,123 @@
+//! Parameter Sources Tests
+//!
+//! This tests whether parameters annotated with `#[path]`,
+//! `#[query]`, `#[header(..)]` and `#[cookie(..)]` are looked
+//! up and parsed, mapping missing or invalid values to the
+//! `#[error_override(..)]` variant if declared.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::cookie::Cookie;
+use actix_web::http::header::HeaderValue;
+use actix_web::test::TestRequest;
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum ApiError {
+    #[error("An API key is required.")]
+    #[status_code(Unauthorized)]
+    MissingKey,
+}
+
+#[proof_route("GET /users/{id}")]
+async fn get_user(
+    #[path] id: u64,
+    #[query] page: Option<u32>,
+    #[query("per_page")] limit: Option<u32>,
+) -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().body(format!("{id} {page:?} {limit:?}")))
+}
+
+#[proof_route("GET /keys")]
+async fn get_key(
+    #[header("X-Api-Key")]
+    #[error_override(MissingKey)]
+    key: String,
+    #[header] user_agent: Option<String>,
+) -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().body(format!("{key} {user_agent:?}")))
+}
+
+#[proof_route("GET /session")]
+async fn get_session(#[cookie("session")] sid: String) -> Result<HttpResponse, ApiError> {
+    Ok(HttpResponse::Ok().body(sid))
+}
+
+async fn request(request: TestRequest) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(get_user)
+            .service(get_key)
+            .service(get_session),
+        request,
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_path_and_query() {
+    assert_eq!(
+        request(TestRequest::get().uri("/users/7?page=2&per_page=10")).await,
+        (200, "7 Some(2) Some(10)".into())
+    );
+    assert_eq!(request(TestRequest::get().uri("/users/7")).await, (200, "7 None None".into()));
+    assert_eq!(
+        request(TestRequest::get().uri("/users/ferris")).await,
+        (404, "Invalid path id.".into())
+    );
+    assert_eq!(
+        request(TestRequest::get().uri("/users/7?page=two")).await,
+        (400, "Invalid query page.".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_headers() {
+    assert_eq!(
+        request(
+            TestRequest::get()
+                .uri("/keys")
+                .insert_header(("X-Api-Key", "secret"))
+                .insert_header(("User-Agent", "tests"))
+        )
+        .await,
+        (200, "secret Some(\"tests\")".into())
+    );
+    assert_eq!(
+        request(TestRequest::get().uri("/keys")).await,
+        (401, "An API key is required.".into())
+    );
+    assert_eq!(
+        request(
+            TestRequest::get()
+                .uri("/keys")
+                .insert_header(("X-Api-Key", "secret"))
+                .insert_header((
+                    "User-Agent",
+                    HeaderValue::from_bytes(b"caf\xe9").expect("Expected a valid header value.")
+                ))
+        )
+        .await,
+        (400, "Invalid header user-agent.".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_cookies() {
+    assert_eq!(
+        request(
+            TestRequest::get()
+                .uri("/session")
+                .cookie(Cookie::new("session", "abc"))
+        )
+        .await,
+        (200, "abc".into())
+    );
+    assert_eq!(
+        request(TestRequest::get().uri("/session")).await,
+        (400, "Missing cookie session.".into())
+    );
+}
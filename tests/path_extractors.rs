@@ -0,0 +1,97 @@
+//! Path Extractors Tests
+//!
+//! This tests whether handlers extracting path segments with
+//! tuples, primitives and structs compile against validated
+//! path templates, including custom regex and tail segments
+//! and tuples ignoring the extra segments.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::Path;
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum PathError {
+    #[error("Something went wrong.")]
+    Internal,
+}
+
+#[derive(Deserialize)]
+struct PostPath {
+    user: String,
+    post: u32,
+    #[serde(default)]
+    draft: bool,
+}
+
+#[proof_route("GET /tuples/{user}/{post:\\d{1,4}}")]
+async fn tuple_path(path: Path<(String, u32)>) -> Result<HttpResponse, PathError> {
+    let (user, post) = path.into_inner();
+
+    Ok(HttpResponse::Ok().body(format!("{user} {post}")))
+}
+
+#[proof_route("GET /structs/{user}/{post}")]
+async fn struct_path(path: Path<PostPath>) -> Result<HttpResponse, PathError> {
+    if path.draft {
+        return Err(PathError::Internal);
+    }
+
+    Ok(HttpResponse::Ok().body(format!("{} {}", path.user, path.post)))
+}
+
+#[proof_route("GET /prefixes/{user}/{post}")]
+async fn prefix_path(path: Path<(String,)>) -> Result<HttpResponse, PathError> {
+    Ok(HttpResponse::Ok().body(
+        path.into_inner()
+            .0,
+    ))
+}
+
+#[proof_route("GET /files/{tail}*")]
+async fn tail_path(tail: Path<String>) -> Result<HttpResponse, PathError> {
+    Ok(HttpResponse::Ok().body(tail.into_inner()))
+}
+
+async fn request(path: &str) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(tuple_path)
+            .service(struct_path)
+            .service(prefix_path)
+            .service(tail_path),
+        TestRequest::get().uri(path),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_tuple_path() {
+    assert_eq!(request("/tuples/ferris/12").await, (200, "ferris 12".into()));
+    assert_eq!(
+        request("/tuples/ferris/12345")
+            .await
+            .0,
+        404
+    );
+}
+
+#[actix_web::test]
+async fn test_struct_path() {
+    assert_eq!(request("/structs/ferris/7").await, (200, "ferris 7".into()));
+}
+
+#[actix_web::test]
+async fn test_tail_path() {
+    assert_eq!(request("/files/a/b/c.txt").await, (200, "a/b/c.txt".into()));
+}
+
+#[actix_web::test]
+async fn test_tuple_prefix_path() {
+    assert_eq!(request("/prefixes/ferris/12").await, (200, "ferris".into()));
+}
@@ -0,0 +1,151 @@
+//! Path To Error Tests
+//!
+//! This tests whether routes declared with `path_to_error = true`
+//! deserialize their `Json`, `Query` and `Form` extractors with
+//! `serde_path_to_error`, exposing the failing field `path`
+//! and the `problem` to the error overrides.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::{Form, Json, Query};
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct Address {
+    zip: u32,
+}
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct CreateUser {
+    email: String,
+    address: Address,
+}
+
+#[derive(Deserialize)]
+#[expect(dead_code)]
+struct Search {
+    page: u32,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+#[default_status_code(UnprocessableEntity)]
+enum TestError {
+    #[error("{path}: {problem}")]
+    InvalidField { path: String, problem: String },
+
+    #[error("{0}")]
+    InvalidQuery(String),
+}
+
+#[proof_route("POST /users", path_to_error = true)]
+async fn create_user(
+    #[error_override(InvalidField {
+        path,
+        problem: expected.map_or(problem, |expected| format!("expected {expected}")),
+    })]
+    _body: Json<CreateUser>,
+    #[error_override(InvalidQuery(format!("{path} {problem}")))] _query: Query<Search>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("POST /forms", path_to_error = true)]
+async fn create_form(_form: Form<Search>) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn request(request: TestRequest) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(create_user)
+            .service(create_form),
+        request,
+    )
+    .await
+}
+
+fn create_user_request(uri: &str, body: serde_json::Value) -> TestRequest {
+    TestRequest::post()
+        .uri(uri)
+        .set_json(body)
+}
+
+#[actix_web::test]
+async fn test_path_to_error_json() {
+    assert_eq!(
+        request(create_user_request(
+            "/users?page=1",
+            serde_json::json!({ "email": "a@b.c", "address": { "zip": "abc" } })
+        ))
+        .await,
+        (422, "address.zip: expected u32".to_owned())
+    );
+    assert_eq!(
+        request(create_user_request(
+            "/users?page=1",
+            serde_json::json!({ "email": "a@b.c", "address": {} })
+        ))
+        .await,
+        (422, "address: missing field `zip`".to_owned())
+    );
+    assert_eq!(
+        request(create_user_request(
+            "/users?page=1",
+            serde_json::json!({ "email": "a@b.c", "address": { "zip": 8000 } })
+        ))
+        .await
+        .0,
+        200
+    );
+}
+
+#[actix_web::test]
+async fn test_path_to_error_query() {
+    let (status, body) = request(create_user_request(
+        "/users?page=first",
+        serde_json::json!({ "email": "a@b.c", "address": { "zip": 8000 } }),
+    ))
+    .await;
+
+    assert_eq!(status, 422);
+    assert!(body.starts_with("page invalid digit found in string"));
+}
+
+#[actix_web::test]
+async fn test_path_to_error_default_response() {
+    let (status, body) = request(
+        TestRequest::post()
+            .uri("/forms")
+            .set_form([("other", "1")]),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+    assert_eq!(body, "Parse error: missing field `page`.");
+
+    let (status, body) = request(
+        TestRequest::post()
+            .uri("/forms")
+            .set_form([("page", "first")]),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+    assert_eq!(body, "Parse error: page: invalid digit found in string.");
+
+    let (status, _) = request(
+        TestRequest::post()
+            .uri("/forms")
+            .set_form([("page", "1")]),
+    )
+    .await;
+
+    assert_eq!(status, 200);
+}
@@ -0,0 +1,100 @@
+//! Responder Return Tests
+//!
+//! This tests whether `proof_route` handlers can return
+//! any `actix_web::Responder` as the success type, including
+//! `impl Responder`.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::web::{Json, Path};
+use actix_web::{Either, Responder};
+use common::test_http_endpoint;
+use serde::Serialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("The user was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+}
+
+#[derive(Serialize)]
+struct User {
+    id: u32,
+}
+
+#[proof_route("GET /{user}")]
+async fn impl_responder(user: Path<String>) -> Result<impl Responder, TestError> {
+    match user.as_str() {
+        "ferris" => Ok(Json(User { id: 7 })),
+        _ => Err(TestError::NotFound),
+    }
+}
+
+#[proof_route("GET /{kind}")]
+async fn either_responder(kind: Path<String>) -> Result<Either<Json<User>, String>, TestError> {
+    match kind.as_str() {
+        "json" => Ok(Either::Left(Json(User { id: 1 }))),
+        _ => Ok(Either::Right("plain".into())),
+    }
+}
+
+test_http_endpoint!(
+    test impl_responder as test_impl_responder_ok
+    with request {
+        head: get /ferris;
+    }
+    and expect response {
+        head: 200;
+        headers: {
+            "content-type": "application/json"
+        }
+        body: {
+            r#"{"id":7}"#
+        }
+    }
+);
+
+test_http_endpoint!(
+    test impl_responder as test_impl_responder_err
+    with request {
+        head: get /unknown;
+    }
+    and expect response {
+        head: 404;
+        body: {
+            "The user was not found."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test either_responder as test_either_responder_json
+    with request {
+        head: get /json;
+    }
+    and expect response {
+        head: 200;
+        body: {
+            r#"{"id":1}"#
+        }
+    }
+);
+
+test_http_endpoint!(
+    test either_responder as test_either_responder_plain
+    with request {
+        head: get /plain;
+    }
+    and expect response {
+        head: 200;
+        headers: {
+            "content-type": "text/plain; charset=utf-8"
+        }
+        body: {
+            "plain"
+        }
+    }
+);
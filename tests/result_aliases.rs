@@ -0,0 +1,89 @@
+//! Result Alias Tests
+//!
+//! This tests whether handlers returning `Result` aliases
+//! work with `proof_route` when the error type is declared
+//! with the `error = ..` option.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use actix_web::web::{Json, Path};
+use common::test_http_endpoint;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum ApiError {
+    #[error("The resource was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+
+    #[error("Invalid body.")]
+    #[status_code(BadRequest)]
+    InvalidBody,
+}
+
+type ApiResult<T> = Result<T, ApiError>;
+
+mod api {
+    pub type Result<T> = std::result::Result<T, super::ApiError>;
+}
+
+#[derive(Deserialize)]
+struct Body {
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[proof_route("GET /{found}", error = ApiError)]
+async fn alias_route(found: Path<bool>) -> ApiResult<HttpResponse> {
+    if found.into_inner() { Ok(HttpResponse::Ok().body("found")) } else { Err(ApiError::NotFound) }
+}
+
+#[proof_route("POST /", error = ApiError)]
+async fn module_alias_route(
+    #[error_override(InvalidBody)] _body: Json<Body>,
+) -> api::Result<HttpResponse> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+test_http_endpoint!(
+    test alias_route as test_result_alias_ok
+    with request {
+        head: get /true;
+    }
+    and expect response {
+        head: 200;
+        body: {
+            "found"
+        }
+    }
+);
+
+test_http_endpoint!(
+    test alias_route as test_result_alias_err
+    with request {
+        head: get /false;
+    }
+    and expect response {
+        head: 404;
+        body: {
+            "The resource was not found."
+        }
+    }
+);
+
+test_http_endpoint!(
+    test module_alias_route as test_module_result_alias_override
+    with request {
+        head: post /;
+        body: { "{}" }
+    }
+    and expect response {
+        head: 400;
+        body: {
+            "Invalid body."
+        }
+    }
+);
@@ -0,0 +1,121 @@
+//! Route Options Tests
+//!
+//! This tests whether the `name`, `guard` and `wrap` options
+//! are forwarded to the generated resources like the ones
+//! of the `actix_web` route macros.
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::guard::GuardContext;
+use actix_web::middleware::DefaultHeaders;
+use actix_web::test::TestRequest;
+use actix_web::{App, HttpRequest, HttpResponse};
+use common::call_app_parts;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum UserError {
+    #[error("The URL could not be generated.")]
+    UrlGeneration,
+}
+
+fn is_json(ctx: &GuardContext) -> bool {
+    ctx.head()
+        .headers()
+        .get("content-type")
+        .is_some_and(|value| value == "application/json")
+}
+
+#[proof_route("GET /users/{id}", name = "user_detail")]
+async fn user_detail() -> Result<HttpResponse, UserError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("GET /links")]
+async fn links(request: HttpRequest) -> Result<HttpResponse, UserError> {
+    let url = request
+        .url_for("user_detail", ["ferris"])
+        .map_err(|_| UserError::UrlGeneration)?;
+
+    Ok(HttpResponse::Ok().body(
+        url.path()
+            .to_string(),
+    ))
+}
+
+#[proof_route("POST /json", guard = "is_json")]
+async fn json_only() -> Result<HttpResponse, UserError> {
+    Ok(HttpResponse::Ok().body("json"))
+}
+
+#[proof_route(
+    "GET /wrapped",
+    wrap = "DefaultHeaders::new().add((\"x-wrapped\", \"first\"))",
+    wrap = "DefaultHeaders::new().add((\"x-outer\", \"second\"))"
+)]
+async fn wrapped() -> Result<HttpResponse, UserError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn request(request: TestRequest) -> (u16, Vec<String>, String) {
+    let (status, headers, body) = call_app_parts(
+        App::new()
+            .service(user_detail)
+            .service(links)
+            .service(json_only)
+            .service(wrapped),
+        request,
+    )
+    .await;
+    let headers = ["x-wrapped", "x-outer"]
+        .into_iter()
+        .filter_map(|name| {
+            headers
+                .get(name)
+                .and_then(|value| {
+                    value
+                        .to_str()
+                        .ok()
+                })
+                .map(ToString::to_string)
+        })
+        .collect();
+
+    (status, headers, String::from_utf8_lossy(&body).into_owned())
+}
+
+#[actix_web::test]
+async fn test_route_name() {
+    assert_eq!(
+        request(TestRequest::get().uri("/links")).await,
+        (200, vec![], "/users/ferris".into())
+    );
+}
+
+#[actix_web::test]
+async fn test_route_guard() {
+    assert_eq!(
+        request(
+            TestRequest::post()
+                .uri("/json")
+                .insert_header(("content-type", "application/json"))
+        )
+        .await,
+        (200, vec![], "json".into())
+    );
+    assert_eq!(
+        request(TestRequest::post().uri("/json"))
+            .await
+            .0,
+        404
+    );
+}
+
+#[actix_web::test]
+async fn test_route_wrap() {
+    assert_eq!(
+        request(TestRequest::get().uri("/wrapped")).await,
+        (200, vec!["first".into(), "second".into()], String::new())
+    );
+}
@@ -0,0 +1,147 @@
+//! Scope Tests
+//!
+//! This tests whether `proof_scope` modules register every
+//! route, nested scope and controller declared inside them
+//! under their prefix, passing down the scope defaults.
+
+use actix_failwrap::{ErrorResponse, proof_scope};
+use actix_web::App;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use common::call_app;
+use thiserror::Error;
+
+mod common;
+
+#[derive(ErrorResponse, Error, Debug)]
+pub enum ApiError {
+    #[error("The resource was not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+
+    #[error("The request is invalid.")]
+    #[status_code(BadRequest)]
+    InvalidRequest,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+pub enum AdminError {
+    #[error("Forbidden.")]
+    #[status_code(Forbidden)]
+    Forbidden,
+}
+
+pub type ApiResult<T> = Result<T, ApiError>;
+
+pub struct StatusController;
+
+#[proof_scope("/api", error = crate::ApiError, extractor_error = InvalidRequest)]
+mod api {
+    use actix_failwrap::{proof_controller, proof_route, proof_scope};
+    use actix_web::HttpResponse;
+
+    use crate::{ApiResult, StatusController};
+
+    #[proof_route("GET /health")]
+    async fn health() -> ApiResult<HttpResponse> {
+        Ok(HttpResponse::Ok().body("healthy"))
+    }
+
+    #[cfg(any())]
+    #[proof_route("GET /disabled")]
+    async fn disabled() -> ApiResult<HttpResponse> {
+        compile_error!("This route must not be compiled.");
+    }
+
+    #[proof_controller("/status")]
+    impl StatusController {
+        #[proof_route("GET /")]
+        async fn status(&self) -> ApiResult<HttpResponse> {
+            Ok(HttpResponse::Ok().body("running"))
+        }
+    }
+
+    #[proof_scope("/users")]
+    mod users {
+        use actix_failwrap::proof_route;
+        use actix_web::HttpResponse;
+
+        use crate::{ApiError, ApiResult};
+
+        #[proof_route("GET /missing")]
+        async fn missing_user() -> ApiResult<HttpResponse> {
+            Err(ApiError::NotFound)
+        }
+    }
+
+    #[proof_scope("/teams/{team}")]
+    mod teams {
+        use actix_failwrap::proof_route;
+        use actix_web::HttpResponse;
+
+        use crate::ApiResult;
+
+        #[proof_route("GET /members/{member}")]
+        async fn member(#[path] team: String, #[path] member: u32) -> ApiResult<HttpResponse> {
+            Ok(HttpResponse::Ok().body(format!("{team} {member}")))
+        }
+    }
+
+    #[proof_scope("/admin", error = crate::AdminError)]
+    mod admin {
+        use actix_failwrap::proof_route;
+        use actix_web::HttpResponse;
+
+        use crate::AdminError;
+
+        #[proof_route("GET /")]
+        async fn dashboard() -> Result<HttpResponse, AdminError> {
+            Err(AdminError::Forbidden)
+        }
+    }
+}
+
+async fn request(path: &str) -> (u16, String) {
+    call_app(
+        App::new()
+            .app_data(Data::new(StatusController))
+            .configure(api::configure),
+        TestRequest::get().uri(path),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_scope_routes() {
+    assert_eq!(request("/api/health").await, (200, "healthy".into()));
+    assert_eq!(
+        request("/api/disabled")
+            .await
+            .0,
+        404
+    );
+}
+
+#[actix_web::test]
+async fn test_scope_controllers() {
+    assert_eq!(request("/api/status/").await, (200, "running".into()));
+}
+
+#[actix_web::test]
+async fn test_nested_scopes() {
+    assert_eq!(request("/api/users/missing").await, (404, "The resource was not found.".into()));
+    assert_eq!(request("/api/admin/").await, (403, "Forbidden.".into()));
+}
+
+#[actix_web::test]
+async fn test_scope_path_segments() {
+    assert_eq!(request("/api/teams/core/members/7").await, (200, "core 7".into()));
+}
+
+#[actix_web::test]
+async fn test_scope_extractor_error() {
+    assert_eq!(
+        request("/api/teams/core/members/seven").await,
+        (400, "The request is invalid.".into())
+    );
+}
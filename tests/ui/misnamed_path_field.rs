@@ -0,0 +1,24 @@
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::web::Path;
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("Not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+}
+
+#[derive(Deserialize)]
+struct PostPath {
+    user: String,
+    post: u32,
+}
+
+#[proof_route("GET /users/{user}/posts/{post_id}")]
+async fn handler(path: Path<PostPath>) -> Result<actix_web::HttpResponse, TestError> {
+    Ok(actix_web::HttpResponse::Ok().body(format!("{} {}", path.user, path.post)))
+}
+
+fn main() {}
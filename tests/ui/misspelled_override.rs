@@ -0,0 +1,20 @@
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use actix_web::web::Json;
+use thiserror::Error;
+
+#[derive(ErrorResponse, Error, Debug)]
+#[default_status_code(BadRequest)]
+enum TestError {
+    #[error("Invalid body.")]
+    InvalidBody,
+}
+
+#[proof_route("POST /")]
+async fn handler(
+    #[error_override(InvalidBdy)] _body: Json<String>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use actix_failwrap::{ErrorResponse, proof_route};
+use thiserror::Error;
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("Not found.")]
+    #[status_code(NotFound)]
+    NotFound,
+}
+
+#[proof_route("GET /users/{id}")]
+async fn handler(#[path] idd: u64) -> Result<actix_web::HttpResponse, TestError> {
+    Ok(actix_web::HttpResponse::Ok().body(idd.to_string()))
+}
+
+fn main() {}
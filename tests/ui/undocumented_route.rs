@@ -0,0 +1,28 @@
+//! The handler docs are forwarded to the generated service.
+
+#![deny(missing_docs)]
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::HttpResponse;
+use thiserror::Error;
+
+/// The handler error.
+#[derive(ErrorResponse, Error, Debug)]
+pub enum TestError {
+    /// The only variant.
+    #[error("Unreachable.")]
+    Unreachable,
+}
+
+/// This route is documented.
+#[proof_route("GET /")]
+pub async fn documented_route() -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route("GET /")]
+pub async fn undocumented_route() -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use actix_failwrap::ErrorResponse;
+use thiserror::Error;
+
+#[derive(ErrorResponse, Error, Debug)]
+#[extractor_errors(Jsn => InvalidBody)]
+enum TestError {
+    #[error("Invalid body.")]
+    InvalidBody,
+}
+
+fn main() {}
@@ -0,0 +1,133 @@
+//! Validation Tests
+//!
+//! This tests whether parameters annotated with `#[validate(..)]`
+//! are validated after the extraction with the `validator` and
+//! `garde` backends, rendering failures as the `on_error`
+//! variant with the per field error map.
+
+use std::collections::BTreeMap;
+
+use actix_failwrap::{ErrorResponse, proof_route};
+use actix_web::test::TestRequest;
+use actix_web::web::{Json, Query};
+use actix_web::{App, HttpResponse};
+use common::call_app;
+use serde::Deserialize;
+use thiserror::Error;
+
+mod common;
+
+#[derive(Deserialize, validator::Validate)]
+struct CreateUser {
+    #[validate(length(min = 3, message = "too short"))]
+    name: String,
+
+    #[validate(range(min = 18))]
+    age: u32,
+}
+
+#[derive(Deserialize, garde::Validate)]
+struct Search {
+    #[garde(length(min = 2))]
+    term: String,
+}
+
+#[derive(ErrorResponse, Error, Debug)]
+enum TestError {
+    #[error("Invalid fields: {}", format_fields(.0))]
+    #[status_code(UnprocessableEntity)]
+    Unprocessable(BTreeMap<String, Vec<String>>),
+}
+
+fn format_fields(fields: &BTreeMap<String, Vec<String>>) -> String {
+    fields
+        .iter()
+        .map(|(field, errors)| format!("{field} ({})", errors.join(", ")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[proof_route("POST /users")]
+async fn create_user(
+    #[validate(backend = validator, on_error = Unprocessable(fields))] body: Json<CreateUser>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(
+        body.name
+            .clone(),
+    ))
+}
+
+#[proof_route("GET /search")]
+async fn search(
+    #[validate(backend = garde, on_error = Unprocessable(fields))] query: Query<Search>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(
+        query
+            .term
+            .clone(),
+    ))
+}
+
+#[proof_route("GET /search/default")]
+async fn search_default(
+    #[validate(backend = garde)] query: Query<Search>,
+) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(
+        query
+            .term
+            .clone(),
+    ))
+}
+
+async fn request(request: TestRequest) -> (u16, String) {
+    call_app(
+        App::new()
+            .service(create_user)
+            .service(search)
+            .service(search_default),
+        request,
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn test_validation_validator() {
+    let (status, body) = request(
+        TestRequest::post()
+            .uri("/users")
+            .set_json(serde_json::json!({ "name": "Jo", "age": 12 })),
+    )
+    .await;
+
+    assert_eq!(status, 422);
+    assert_eq!(body, "Invalid fields: age (range), name (too short)");
+
+    let (status, body) = request(
+        TestRequest::post()
+            .uri("/users")
+            .set_json(serde_json::json!({ "name": "John", "age": 30 })),
+    )
+    .await;
+
+    assert_eq!((status, body.as_str()), (200, "John"));
+}
+
+#[actix_web::test]
+async fn test_validation_garde() {
+    let (status, body) = request(TestRequest::get().uri("/search?term=a")).await;
+
+    assert_eq!(status, 422);
+    assert!(body.starts_with("Invalid fields: term (length is lower than 2"));
+
+    let (status, body) = request(TestRequest::get().uri("/search?term=abc")).await;
+
+    assert_eq!((status, body.as_str()), (200, "abc"));
+}
+
+#[actix_web::test]
+async fn test_validation_default_response() {
+    let (status, body) = request(TestRequest::get().uri("/search/default?term=a")).await;
+
+    assert_eq!(status, 422);
+    assert!(body.starts_with("term: length is lower than 2"));
+}